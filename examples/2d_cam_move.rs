@@ -8,13 +8,14 @@ const SPEED: f32 = 4.5;
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, DollyPlugin))
         .add_systems(Startup, setup)
+        .add_systems(Update, update_camera)
         //If large amount of smoothing is used, where camera movement is expected beyond the time of input
         //Ie. motion smoothing beyond 0.25, use update_2d_active_continuous instead
         .add_systems(
-            Update,
-            (Dolly::<MainCamera>::update_2d_active, update_camera),
+            PostUpdate,
+            Dolly::<MainCamera>::update_2d_active.in_set(DollyApplySet),
         )
         .run();
 }
@@ -37,9 +38,11 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn((
         Camera2d,
         MainCamera,
-        Rig::builder()
-            .with(Position::default())
-            .with(Smooth::new_position(0.25))
+        Rig2d::builder()
+            .with(Position2d::default())
+            .with(Roll::default())
+            .with(Zoom::default())
+            .with(Smooth2d::new(0.25, 0.25, 0.25))
             .build(),
     ));
 
@@ -50,34 +53,58 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     info!("Use W, A, S, D for movement");
     info!("Use Z & X zooming in and out");
+    info!("Use Q & E to roll the camera");
+    info!("Use P to toggle snapping to whole pixels and zoom levels");
 }
 
-fn update_camera(keys: Res<ButtonInput<KeyCode>>, mut query: Query<&mut Rig>) {
+fn update_camera(keys: Res<ButtonInput<KeyCode>>, mut query: Query<&mut Rig2d>) {
     for mut rig in &mut query {
+        if keys.just_pressed(KeyCode::KeyP) {
+            rig.pixel_snap = match rig.pixel_snap {
+                Some(_) => None,
+                None => Some(PixelSnap::new().with_integer_zoom(true)),
+            };
+            println!("Pixel snap {}", rig.pixel_snap.is_some());
+        }
+
         for &key in keys.get_pressed() {
-            let pos_driver = rig.try_driver_mut::<Position>();
+            let pos_driver = rig.try_driver_mut::<Position2d>();
             if let Some(pos) = pos_driver {
                 if key == KeyCode::KeyW {
-                    pos.translate(SPEED * Vec3::Y);
+                    pos.translate(SPEED * Vec2::Y);
                 }
                 if key == KeyCode::KeyA {
-                    pos.translate(SPEED * -Vec3::X);
+                    pos.translate(SPEED * -Vec2::X);
                 }
                 if key == KeyCode::KeyS {
-                    pos.translate(SPEED * -Vec3::Y);
+                    pos.translate(SPEED * -Vec2::Y);
                 }
                 if key == KeyCode::KeyD {
-                    pos.translate(SPEED * Vec3::X);
+                    pos.translate(SPEED * Vec2::X);
                 }
+            }
+
+            let zoom_driver = rig.try_driver_mut::<Zoom>();
+            if let Some(zoom) = zoom_driver {
                 if key == KeyCode::KeyZ {
-                    pos.translate(SPEED * -Vec3::Z);
+                    zoom.zoom_by(1.01);
                 }
                 if key == KeyCode::KeyX {
-                    pos.translate(SPEED * Vec3::Z);
+                    zoom.zoom_by(1. / 1.01);
+                }
+            }
+
+            let roll_driver = rig.try_driver_mut::<Roll>();
+            if let Some(roll) = roll_driver {
+                if key == KeyCode::KeyQ {
+                    roll.rotate(0.02);
+                }
+                if key == KeyCode::KeyE {
+                    roll.rotate(-0.02);
                 }
             }
 
-            let smooth_driver = rig.try_driver_mut::<Smooth>();
+            let smooth_driver = rig.try_driver_mut::<Smooth2d>();
             if let Some(smooth) = smooth_driver {
                 if key == KeyCode::KeyC {
                     smooth.position_smoothness = (smooth.position_smoothness - 0.001).abs();
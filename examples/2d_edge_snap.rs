@@ -1,17 +1,22 @@
-use bevy::prelude::*;
+use bevy::{math::bounding::Aabb2d, prelude::*};
 use bevy_dolly::prelude::*;
 
+const RUN_SPEED: f32 = 400.;
+const JUMP_SPEED: f32 = 900.;
+const GRAVITY: f32 = 2400.;
+/// Distance from the center of the player to its feet
+const HALF_HEIGHT: f32 = 64.;
+const FLOOR: f32 = -64.;
+const WALL: f32 = 950.;
+
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, DollyPlugin))
         .add_systems(Startup, setup)
+        .add_systems(Update, player_movement)
         .add_systems(
-            Update,
-            (
-                Dolly::<MainCamera>::update_2d_active,
-                update_camera,
-                sprite_movement,
-            ),
+            PostUpdate,
+            Dolly::<MainCamera>::update_2d_active.in_set(DollyApplySet),
         )
         .run();
 }
@@ -19,10 +24,15 @@ fn main() {
 #[derive(Component)]
 struct MainCamera;
 
+#[derive(Component, Default)]
+struct Player {
+    velocity: Vec2,
+}
+
+/// A platform the player can land on from above
 #[derive(Component)]
-enum Direction {
-    Left,
-    Right,
+struct Platform {
+    half_size: Vec2,
 }
 
 fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -30,15 +40,14 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     let mut dolly = Sprite::from_image(asset_server.load("bevy_dolly.png"));
     dolly.custom_size = Some(Vec2::new(128., 128.));
-    commands.spawn((
-        dolly,
-        offset_transform.with_translation(Vec3 {
-            x: 0.,
-            y: 0.,
-            z: 1.,
-        }),
-        Direction::Right,
-    ));
+    let player = commands
+        .spawn((
+            dolly,
+            Transform::from_xyz(0., 0., 1.),
+            Player::default(),
+            PlatformerState::default(),
+        ))
+        .id();
 
     let mut room = Sprite::from_image(asset_server.load("room.png"));
     room.custom_size = Some(Vec2::new(2.6 * 800., 800.));
@@ -57,40 +66,98 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     room_end.flip_x = true;
     commands.spawn((room_end, Transform::from_xyz(-1016., -104.5, 2.0)));
 
+    for (position, width) in [
+        (Vec2::new(-500., 30.), 300.),
+        (Vec2::new(-50., 180.), 250.),
+        (Vec2::new(400., 330.), 300.),
+    ] {
+        let half_size = Vec2::new(width, 20.) / 2.;
+        commands.spawn((
+            Sprite::from_color(Color::srgb(0.35, 0.25, 0.2), half_size * 2.),
+            Transform::from_translation(position.extend(0.5)),
+            Platform { half_size },
+        ));
+    }
+
     commands.spawn((
         MainCamera,
-        Rig::builder()
-            .with(Position::new(Vec3::new(0., 0., 0.)))
-            .with(Smooth::new_position(1.2))
+        Rig2d::builder()
+            // Follows the player sideways, but only moves up or down once it lands on a platform
+            // or falls out of the window
+            .with(
+                PlatformSnap2d::new(player)
+                    .with_offset(Vec2::new(0., 100.))
+                    .with_vertical_window(100., 250.)
+                    .with_damping(0.2, 0.6)
+                    .with_forward_focus(150., 1.),
+            )
+            // Keeps the view inside the room
+            .with(Confiner::new(ConfinerBounds::Rect(Aabb2d {
+                min: Vec2::new(-940., -360.),
+                max: Vec2::new(1140., 600.),
+            })))
             .build(),
         Camera2d,
     ));
+
+    info!("Use A & D to run and Space to jump");
 }
 
-/// The sprite is animated by changing its translation depending on the time that has passed since
-/// the last frame.
-fn sprite_movement(time: Res<Time>, mut sprite_position: Query<(&mut Direction, &mut Transform)>) {
-    for (mut logo, mut transform) in &mut sprite_position {
-        match *logo {
-            Direction::Right => transform.translation.x += 400. * time.delta_secs(),
-            Direction::Left => transform.translation.x -= 400. * time.delta_secs(),
+fn player_movement(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut players: Query<(
+        &mut Player,
+        &mut PlatformerState,
+        &mut Transform,
+        &mut Sprite,
+    )>,
+    platforms: Query<(&Platform, &Transform), Without<Player>>,
+) {
+    let delta_time = time.delta_secs();
+
+    for (mut player, mut state, mut transform, mut sprite) in &mut players {
+        let mut input = 0.;
+        if keys.pressed(KeyCode::KeyA) {
+            input -= 1.;
+        }
+        if keys.pressed(KeyCode::KeyD) {
+            input += 1.;
+        }
+        if input != 0. {
+            sprite.flip_x = input < 0.;
         }
 
-        if transform.translation.x > 1200. {
-            *logo = Direction::Left;
-        } else if transform.translation.x < -1200. {
-            *logo = Direction::Right;
+        player.velocity.x = input * RUN_SPEED;
+        if state.grounded && keys.just_pressed(KeyCode::Space) {
+            player.velocity.y = JUMP_SPEED;
         }
-    }
-}
+        player.velocity.y -= GRAVITY * delta_time;
 
-fn update_camera(sprite_position: Query<(&Direction, &Transform)>, mut q0: Query<&mut Rig>) {
-    let mut rig = q0.single_mut();
-    let camera_driver = rig.driver_mut::<Position>();
+        let last_feet = transform.translation.y - HALF_HEIGHT;
+        transform.translation += (player.velocity * delta_time).extend(0.);
+        transform.translation.x = transform.translation.x.clamp(-WALL, WALL);
+        let feet = transform.translation.y - HALF_HEIGHT;
 
-    for (_dir, pos) in &sprite_position {
-        if pos.translation.x < 495. && pos.translation.x > -295. {
-            camera_driver.position = pos.translation;
+        // Lands on the floor, or on a platform it fell onto this frame
+        let ground = platforms
+            .iter()
+            .filter_map(|(platform, platform_transform)| {
+                let top = platform_transform.translation.y + platform.half_size.y;
+                let over = (transform.translation.x - platform_transform.translation.x).abs()
+                    < platform.half_size.x;
+                (over && player.velocity.y <= 0. && last_feet >= top && feet <= top).then_some(top)
+            })
+            .chain((feet <= FLOOR).then_some(FLOOR))
+            .reduce(f32::max);
+        if let Some(ground) = ground {
+            transform.translation.y = ground + HALF_HEIGHT;
+            player.velocity.y = 0.;
         }
+
+        state.set_if_neq(PlatformerState {
+            grounded: ground.is_some(),
+            facing: if input != 0. { input } else { state.facing },
+        });
     }
 }
@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{math::bounding::Aabb2d, prelude::*};
 use bevy_dolly::prelude::*;
 
 fn main() {
@@ -6,10 +6,9 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_systems(Startup, setup)
         .add_systems(PreUpdate, sprite_movement)
-        .add_systems(Update, update_camera)
-        .add_systems(Update, Dolly::<MainCamera>::update_2d_active_continuous)
-        // So gizmo is drawn after update as to not jump during camera movement
-        .add_systems(PostUpdate, draw_gizmo)
+        .add_dolly_2d_component(MainCamera)
+        // So gizmo is drawn after the camera moved as to not jump during camera movement
+        .add_systems(PostUpdate, draw_gizmo.after(DollyUpdateSet))
         .run();
 }
 
@@ -34,27 +33,31 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     let mut dolly = Sprite::from_image(asset_server.load("bevy_dolly.png"));
     dolly.custom_size = Some(Vec2::new(128., 128.));
 
-    commands.spawn((
-        dolly.clone(),
-        offset_transform.with_translation(Vec3 {
-            x: 0.,
-            y: 0.,
-            z: 1.,
-        }),
-        Direction::Right,
-        PlayerOne,
-    ));
+    let player_one = commands
+        .spawn((
+            dolly.clone(),
+            offset_transform.with_translation(Vec3 {
+                x: 0.,
+                y: 0.,
+                z: 1.,
+            }),
+            Direction::Right,
+            PlayerOne,
+        ))
+        .id();
 
     dolly.flip_x = true;
-    commands.spawn((
-        dolly,
-        offset_transform.with_translation(Vec3 {
-            x: 0.,
-            y: 0.,
-            z: 1.,
-        }),
-        PlayerTwo,
-    ));
+    let player_two = commands
+        .spawn((
+            dolly,
+            offset_transform.with_translation(Vec3 {
+                x: 0.,
+                y: 0.,
+                z: 1.,
+            }),
+            PlayerTwo,
+        ))
+        .id();
 
     let mut room = Sprite::from_image(asset_server.load("room.png"));
     room.custom_size = Some(Vec2::new(2.6 * 800., 800.));
@@ -75,9 +78,24 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     commands.spawn((
         MainCamera,
-        Rig::builder()
-            .with(Position::new(Vec3::new(0., 0., 0.)))
-            .with(Smooth::new_position(1.2))
+        Rig2d::builder()
+            // Keeps both players in frame, zooming out as they move apart
+            .with(
+                TargetGroup::new()
+                    .with_target(player_one, 1., 64.)
+                    .with_target(player_two, 1., 64.)
+                    .with_padding(100.)
+                    .with_zoom_limits(0.5, 1.5),
+            )
+            .with(Smooth2d::new(1.2, 0., 0.5))
+            // Keeps the view inside the room
+            .with(
+                Confiner::new(ConfinerBounds::Rect(Aabb2d {
+                    min: Vec2::new(-940., -360.),
+                    max: Vec2::new(1140., 600.),
+                }))
+                .with_damping(0.5),
+            )
             .build(),
         Camera2d,
     ));
@@ -105,14 +123,3 @@ fn sprite_movement(time: Res<Time>, mut sprite_position: Query<(&mut Direction,
         }
     }
 }
-
-fn update_camera(sprite_position: Query<(&Direction, &Transform)>, mut q0: Query<&mut Rig>) {
-    let mut rig = q0.single_mut();
-    let camera_driver = rig.driver_mut::<Position>();
-
-    for (_dir, pos) in &sprite_position {
-        if pos.translation.x < 495. && pos.translation.x > -295. {
-            camera_driver.position = pos.translation;
-        }
-    }
-}
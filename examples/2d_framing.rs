@@ -0,0 +1,128 @@
+use bevy::{math::bounding::Aabb2d, prelude::*};
+use bevy_dolly::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DollyPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, sprite_movement)
+        .add_systems(
+            PostUpdate,
+            (
+                Dolly::<MainCamera>::update_2d_active.in_set(DollyApplySet),
+                // Drawn after the camera moved, so the zones don't jump during camera movement
+                draw_zones.after(DollyUpdateSet),
+            ),
+        )
+        .run();
+}
+
+#[derive(Component)]
+struct MainCamera;
+
+#[derive(Component)]
+enum Direction {
+    Left,
+    Right,
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let offset_transform = Transform::from_xyz(100., 0., 0.);
+
+    let mut dolly = Sprite::from_image(asset_server.load("bevy_dolly.png"));
+    dolly.custom_size = Some(Vec2::new(128., 128.));
+    let player = commands
+        .spawn((
+            dolly,
+            offset_transform.with_translation(Vec3 {
+                x: 0.,
+                y: 0.,
+                z: 1.,
+            }),
+            Direction::Right,
+        ))
+        .id();
+
+    let mut room = Sprite::from_image(asset_server.load("room.png"));
+    room.custom_size = Some(Vec2::new(2.6 * 800., 800.));
+    commands.spawn((
+        room,
+        offset_transform.with_translation(Vec3 {
+            x: 0.,
+            y: 200.,
+            z: 0.,
+        }),
+    ));
+
+    let mut room_end = Sprite::from_image(asset_server.load("room_end.png"));
+    commands.spawn((room_end.clone(), Transform::from_xyz(1016., -104.5, 2.0)));
+
+    room_end.flip_x = true;
+    commands.spawn((room_end, Transform::from_xyz(-1016., -104.5, 2.0)));
+
+    commands.spawn((
+        MainCamera,
+        Rig2d::builder()
+            .with(Position2d::new(Vec2::ZERO))
+            // Only moves once the player leaves the dead zone, framed low to show more of the room
+            .with(
+                Framing::new(player)
+                    .with_screen_position(Vec2::new(0., -0.2))
+                    .with_dead_zone(Vec2::new(0.15, 0.1))
+                    .with_soft_zone(Vec2::new(0.6, 0.5))
+                    .with_damping(0.6),
+            )
+            // Leads the camera in the direction the player runs
+            .with(
+                LookAhead::new(player)
+                    .with_axes(Vec3::X)
+                    .with_time(0.4)
+                    .with_max_distance(200.)
+                    .with_smoothness(0.8, 1.5),
+            )
+            // Keeps the view inside the room
+            .with(Confiner::new(ConfinerBounds::Rect(Aabb2d {
+                min: Vec2::new(-940., -360.),
+                max: Vec2::new(1140., 600.),
+            })))
+            .build(),
+        Camera2d,
+    ));
+}
+
+/// The sprite is animated by changing its translation depending on the time that has passed since
+/// the last frame.
+fn sprite_movement(time: Res<Time>, mut sprite_position: Query<(&mut Direction, &mut Transform)>) {
+    for (mut logo, mut transform) in &mut sprite_position {
+        match *logo {
+            Direction::Right => transform.translation.x += 400. * time.delta_secs(),
+            Direction::Left => transform.translation.x -= 400. * time.delta_secs(),
+        }
+
+        if transform.translation.x > 1200. {
+            *logo = Direction::Left;
+        } else if transform.translation.x < -1200. {
+            *logo = Direction::Right;
+        }
+    }
+}
+
+fn draw_zones(
+    mut gizmos: Gizmos,
+    cameras: Query<(&Transform, &OrthographicProjection, &Rig2d), With<MainCamera>>,
+) {
+    for (transform, projection, rig) in &cameras {
+        let Some(framing) = rig.try_driver::<Framing>() else {
+            continue;
+        };
+        let half_size = projection.area.half_size();
+        // The zones move with the camera before it's led ahead
+        let look_ahead = rig
+            .try_driver::<LookAhead>()
+            .map_or(Vec2::ZERO, |look_ahead| look_ahead.offset().truncate());
+        let center =
+            transform.translation.truncate() - look_ahead + framing.screen_position * half_size;
+        gizmos.rect_2d(center, framing.dead_zone * half_size * 2., Color::BLACK);
+        gizmos.rect_2d(center, framing.soft_zone * half_size * 2., Color::WHITE);
+    }
+}
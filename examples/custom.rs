@@ -8,15 +8,12 @@ struct MainCamera;
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, DollyPlugin))
         .add_systems(Startup, setup)
+        .add_systems(Update, (rotator_system, update_camera).chain())
         .add_systems(
-            Update,
-            (
-                Dolly::<MainCamera>::update_active,
-                update_camera,
-                rotator_system,
-            ),
+            PostUpdate,
+            Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
         )
         .run();
 }
@@ -1,22 +1,19 @@
 use std::f32::consts::PI;
 
 use bevy::prelude::*;
-use bevy_dolly::{drivers::follow::MovableLookAt, prelude::*};
+use bevy_dolly::prelude::*;
 
 #[derive(Component)]
 struct MainCamera;
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, DollyPlugin))
         .add_systems(Startup, setup)
+        .add_systems(Update, rotator_system)
         .add_systems(
-            Update,
-            (
-                Dolly::<MainCamera>::update_active,
-                update_camera,
-                rotator_system,
-            ),
+            PostUpdate,
+            Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
         )
         .run();
 }
@@ -34,20 +31,30 @@ fn setup(
         MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
     ));
 
-    let start_pos = Vec3::new(0., 0., 0.);
-
     let poly_dolly = asset_server.load(GltfAssetLabel::Scene(0).from_asset("poly_dolly.gltf"));
 
-    commands.spawn((
-        SceneRoot(poly_dolly),
-        Transform::from_xyz(0., 0.2, 0.),
-        Rotates,
-    ));
+    let sheep = commands
+        .spawn((
+            SceneRoot(poly_dolly),
+            Transform::from_xyz(0., 0.2, 0.),
+            Rotates,
+        ))
+        .id();
 
+    // The drivers read the sheep's transform every frame
     commands.spawn((
         MainCamera,
         Rig::builder()
-            .with(MovableLookAt::from_position_target(start_pos))
+            .with(FollowEntity::new(sheep).with_rotation(true))
+            .with(Smooth::new_position(1.25).predictive(true))
+            .with(Arm::new(Vec3::new(0.0, 1.5, -3.5)))
+            .with(Smooth::new_position(2.5))
+            .with(
+                LookAtEntity::new(sheep)
+                    .with_offset(Vec3::Y)
+                    .tracking_smoothness(1.25)
+                    .tracking_predictive(true),
+            )
             .build(),
         Camera3d::default(),
         Transform::from_xyz(-2.0, 1., 5.0).looking_at(Vec3::ZERO, Vec3::Y),
@@ -57,14 +64,6 @@ fn setup(
     commands.spawn((PointLight::default(), Transform::from_xyz(4.0, 8.0, 4.0)));
 }
 
-fn update_camera(q0: Query<&Transform, With<Rotates>>, mut q1: Query<&mut Rig>) {
-    let player = q0.single().to_owned();
-    let mut rig = q1.single_mut();
-
-    rig.driver_mut::<MovableLookAt>()
-        .set_position_target(player.translation, player.rotation);
-}
-
 #[derive(Component)]
 struct Rotates;
 
@@ -4,10 +4,18 @@ use bevy_inspector_egui::quick::WorldInspectorPlugin;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, DollyPosCtrl))
+        .add_plugins((DefaultPlugins, DollyPlugin, DollyPosCtrl))
         .add_plugins(WorldInspectorPlugin::new())
+        // The player is spawned below, so the camera can look at it
+        .insert_resource(DollyPosCtrlConfig {
+            player: DollyCameraPlayer::None,
+            ..default()
+        })
         .add_systems(Startup, setup)
-        .add_systems(Update, (Dolly::<MainCamera>::update_active, update_camera))
+        .add_systems(
+            PostUpdate,
+            Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+        )
         .run();
 }
 
@@ -25,11 +33,20 @@ fn setup(
         MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
     ));
 
+    let player = commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::new(0.2, 0.2, 0.2))),
+            MeshMaterial3d(materials.add(Color::srgb(1.0, 0.0, 0.0))),
+            Transform::from_xyz(0., 0.5, 0.),
+            DollyPosCtrlMove,
+        ))
+        .id();
+
     commands.spawn((
         MainCamera,
         Rig::builder()
             .with(Position::new(Vec3::Y * 3.0))
-            .with(LookAt::new(Vec3::new(0., -2., 2.)))
+            .with(LookAtEntity::new(player))
             .build(),
         Camera3d::default(),
         Transform::from_xyz(-2.0, 1., 5.0).looking_at(Vec3::ZERO, Vec3::Y),
@@ -41,16 +58,5 @@ fn setup(
     info!("Use W, A, S, D for movement");
     info!("Use Space and Shift for going up and down");
     info!("Use , (Comma) and . (Period) to rotate Left or Right");
-}
-
-#[allow(clippy::type_complexity)]
-fn update_camera(
-    mut query: ParamSet<(
-        Query<&mut Transform, With<DollyPosCtrlMove>>,
-        Query<&mut Rig>,
-    )>,
-) {
-    let mut p0 = query.p0();
-    let player = p0.single_mut();
-    query.p1().single_mut().driver_mut::<LookAt>().target = player.translation;
+    info!("Expand the Rig component of the camera in the inspector to tweak its drivers");
 }
@@ -9,9 +9,13 @@ struct MainCamera;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, DollyPosCtrl))
+        .add_plugins((DefaultPlugins, DollyPlugin, DollyPosCtrl))
         .add_systems(Startup, setup)
-        .add_systems(Update, (Dolly::<MainCamera>::update_active, update_camera))
+        .add_systems(Update, update_camera)
+        .add_systems(
+            PostUpdate,
+            Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+        )
         .run();
 }
 #[allow(dead_code)]
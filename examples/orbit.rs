@@ -1,5 +1,4 @@
 #![allow(clippy::type_complexity)]
-use bevy::input::mouse::{MouseMotion, MouseWheel};
 use bevy::prelude::*;
 use bevy::render::camera::ScalingMode;
 use bevy_dolly::prelude::*;
@@ -7,27 +6,27 @@ use bevy_dolly::prelude::*;
 #[derive(Component)]
 struct MainCamera;
 
+// The camera has a DollyBrain that blends between an orthographic and a perspective orbit rig,
+// whichever has the highest RigPriority
+
+#[derive(Component)]
+struct OrthographicRig;
+
 #[derive(Component)]
-struct SecondCamera;
+struct PerspectiveRig;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, DollyPosCtrl, DollyCursorGrab))
+        .add_plugins((DefaultPlugins, DollyPlugin, DollyPosCtrl, DollyOrbitCtrl))
         .insert_resource(DollyPosCtrlConfig {
             ..Default::default()
         })
         .init_state::<ProjectionType>()
-        .init_state::<Pan>()
-        .init_state::<ZoomType>()
         .add_systems(Startup, setup)
+        .add_systems(Update, (swap_camera, toggle_pin))
         .add_systems(
-            Update,
-            (
-                Dolly::<MainCamera>::update_active,
-                update_camera,
-                swap_camera,
-                handle_mouse_scroll,
-            ),
+            PostUpdate,
+            Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
         )
         .run();
 }
@@ -39,20 +38,6 @@ enum ProjectionType {
     Perspective,
 }
 
-#[derive(States, Default, PartialEq, Eq, Debug, Clone, Copy, Hash)]
-enum Pan {
-    #[default]
-    Mouse,
-    Keys,
-}
-
-#[derive(States, Default, PartialEq, Eq, Debug, Clone, Copy, Hash)]
-enum ZoomType {
-    #[default]
-    Arm,
-    Fov,
-}
-
 /// set up a simple 3D scene
 fn setup(
     mut commands: Commands,
@@ -69,177 +54,114 @@ fn setup(
 
     let poly_dolly = asset_server.load(GltfAssetLabel::Scene(0).from_asset("poly_dolly.gltf"));
 
-    commands.spawn((
-        SceneRoot(poly_dolly),
-        Transform::from_xyz(0., 0.2, 0.),
-        DollyPosCtrlMove,
-    ));
+    let sheep = commands
+        .spawn((
+            SceneRoot(poly_dolly),
+            Transform::from_xyz(0., 0.2, 0.),
+            DollyPosCtrlMove,
+        ))
+        .id();
 
-    commands.spawn((
-        MainCamera,
+    let orbit_rig = || {
         Rig::builder()
-            .with(Position::new(Vec3::ZERO))
+            .with(FollowEntity::new(sheep).with_offset(Vec3::Y))
             .with(YawPitch::new().yaw_degrees(45.0).pitch_degrees(-30.0))
             .with(Smooth::new_position(0.3))
             .with(Smooth::new_rotation(0.3))
             .with(Arm::new(Vec3::Z * 4.0))
-            .build(),
+    };
+    let orbit_ctrl = |enabled| DollyOrbitCtrlConfig {
+        enabled,
+        zoom_to_cursor: true,
+        ..default()
+    };
+
+    let orthographic = *startup_perspective == ProjectionType::Orthographic;
+    commands.spawn((
+        MainCamera,
+        OrthographicRig,
+        RigPriority::new(orthographic as i32),
+        orbit_rig().with_lens(OrthographicZoom::new(3.0)).build(),
+        orbit_ctrl(orthographic),
+    ));
+    commands.spawn((
+        MainCamera,
+        PerspectiveRig,
+        RigPriority::new(!orthographic as i32),
+        orbit_rig().build(),
+        orbit_ctrl(!orthographic),
     ));
 
-    let start_transform = Transform::from_xyz(10.0, 10.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y);
-
-    let orth = Projection::from(OrthographicProjection {
-        scaling_mode: ScalingMode::FixedVertical {
-            viewport_height: 2.0,
-        },
-        scale: 3.,
-        ..OrthographicProjection::default_3d()
-    });
-
-    let pers = Projection::from(PerspectiveProjection::default());
-
-    if *startup_perspective == ProjectionType::Orthographic {
-        commands.spawn((MainCamera, orth, start_transform));
-        commands.spawn((SecondCamera, pers, start_transform));
-    } else {
-        commands.spawn((MainCamera, pers, start_transform));
-        commands.spawn((SecondCamera, orth, start_transform));
-    }
+    commands.spawn((
+        MainCamera,
+        Camera3d::default(),
+        DollyBrain::new(0.5),
+        projection(*startup_perspective.get()),
+        Transform::from_xyz(10.0, 10.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
 
     // light
     commands.spawn((PointLight::default(), Transform::from_xyz(4.0, 8.0, 4.0)));
 
     info!("Use W, A, S, D for movement");
-    info!("Use Space and Shift for going up and down");
-    info!("Use Z and X to orbit the sheep");
-    info!("Or press E to toggle to use the mouse to orbit the sheep");
+    info!("Drag with the left mouse button to orbit the sheep, or press Z and X");
+    info!("Drag with the middle mouse button to pan");
+    info!("Scroll to zoom towards the cursor");
     info!("Press T to toggle between orthographic and perspective camera");
-    info!("Scroll to Zoom (Press G to switch between changing arm length and fov for perspective and scale for orthographic)");
     info!("Press P to toggle pinned to entity with DollyPosCtrlMove component");
-    info!("Press Esc to toggle cursor focus");
 }
 
-#[allow(clippy::too_many_arguments)]
+fn projection(projection_type: ProjectionType) -> Projection {
+    match projection_type {
+        ProjectionType::Orthographic => Projection::from(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: 2.0,
+            },
+            scale: 3.,
+            ..OrthographicProjection::default_3d()
+        }),
+        ProjectionType::Perspective => Projection::from(PerspectiveProjection::default()),
+    }
+}
+
 fn swap_camera(
     keys: Res<ButtonInput<KeyCode>>,
-    mut commands: Commands,
     perspective: Res<State<ProjectionType>>,
     mut next_perspective: ResMut<NextState<ProjectionType>>,
-    zoom: Res<State<ZoomType>>,
-    mut next_zoom: ResMut<NextState<ZoomType>>,
-    mut q_main: Query<(Entity, &mut Camera), (With<MainCamera>, Without<SecondCamera>)>,
-    mut q_sec: Query<(Entity, &mut Camera), (With<SecondCamera>, Without<MainCamera>)>,
+    mut cameras: Query<&mut Projection, With<DollyBrain>>,
+    mut rigs: Query<(
+        &mut RigPriority,
+        &mut DollyOrbitCtrlConfig,
+        Has<OrthographicRig>,
+    )>,
 ) {
     if keys.just_pressed(KeyCode::KeyT) {
-        if let Ok((e_main, cam_main)) = &mut q_main.get_single_mut() {
-            if let Ok((e_sec, cam_sec)) = &mut q_sec.get_single_mut() {
-                commands
-                    .entity(*e_main)
-                    .remove::<MainCamera>()
-                    .insert(SecondCamera);
-                commands
-                    .entity(*e_sec)
-                    .remove::<SecondCamera>()
-                    .insert(MainCamera);
-                cam_sec.is_active = true;
-                cam_main.is_active = false;
-
-                next_perspective.set(if *perspective == ProjectionType::Orthographic {
-                    ProjectionType::Perspective
-                } else {
-                    ProjectionType::Orthographic
-                });
+        let next = if *perspective == ProjectionType::Orthographic {
+            ProjectionType::Perspective
+        } else {
+            ProjectionType::Orthographic
+        };
 
-                println!("Perspective: {:?}", perspective);
-            }
+        // The brain blends to the rig of the new projection, only that rig is orbited
+        for (mut priority, mut config, orthographic) in &mut rigs {
+            let live = orthographic == (next == ProjectionType::Orthographic);
+            priority.priority = live as i32;
+            config.enabled = live;
         }
-    } else if keys.just_pressed(KeyCode::KeyG) {
-        // Arm doesn't make a difference for Orthographic projection
-        next_zoom.set(
-            if *zoom == ZoomType::Arm && *perspective == ProjectionType::Perspective {
-                ZoomType::Fov
-            } else {
-                ZoomType::Arm
-            },
-        );
-        println!("ZoomType: {:?}", zoom);
-    }
-}
-
-fn handle_mouse_scroll(
-    mut mouse_wheel_events: EventReader<MouseWheel>,
-    mut q_main: Query<&mut Projection, With<MainCamera>>,
-    zoom: Res<State<ZoomType>>,
-    mut rig_q: Query<&mut Rig>,
-) {
-    for mouse_wheel_event in mouse_wheel_events.read() {
-        for mut projection in &mut q_main.iter_mut() {
-            match &mut projection.as_mut() {
-                Projection::Perspective(pers) => {
-                    if *zoom == ZoomType::Fov {
-                        pers.fov = (pers.fov - mouse_wheel_event.y * 0.01).abs();
-                    } else if let Ok(mut rig) = rig_q.get_single_mut() {
-                        if let Some(arm) = rig.try_driver_mut::<Arm>() {
-                            let mut xz = arm.offset;
-                            xz.z = (xz.z - mouse_wheel_event.y * 0.5).abs();
-                            arm.offset = xz;
-                        }
-                    }
-                }
-                Projection::Orthographic(orth) => {
-                    orth.scale = (orth.scale - mouse_wheel_event.y * 0.1).abs();
-                }
-            }
+        for mut camera_projection in &mut cameras {
+            *camera_projection = projection(next);
         }
+        next_perspective.set(next);
+
+        println!("Perspective: {:?}", next);
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn update_camera(
+fn toggle_pin(
     keys: Res<ButtonInput<KeyCode>>,
-    pan: Res<State<Pan>>,
-    mut next_pan: ResMut<NextState<Pan>>,
-    mut mouse_motion_events: EventReader<MouseMotion>,
     mut rig_q: Query<&mut Rig>,
-    trans: Query<&Transform, With<DollyPosCtrlMove>>,
     mut config: ResMut<DollyPosCtrlConfig>,
-    grab_config: Res<DollyCursorGrabConfig>,
 ) {
-    let mut rig = rig_q.single_mut();
-    let camera_yp = rig.driver_mut::<YawPitch>();
-    let sensitivity = Vec2::splat(2.0);
-
-    let mut delta = Vec2::ZERO;
-    for event in mouse_motion_events.read() {
-        delta += event.delta;
-    }
-
-    config.transform.rotation = Quat::from_rotation_y(delta.x);
-
-    if *pan == Pan::Keys {
-        if keys.just_pressed(KeyCode::KeyZ) {
-            camera_yp.rotate_yaw_pitch(-90.0, 0.0);
-        }
-        if keys.just_pressed(KeyCode::KeyX) {
-            camera_yp.rotate_yaw_pitch(90.0, 0.0);
-        }
-    } else if !grab_config.visible {
-        camera_yp.rotate_yaw_pitch(
-            -0.1 * delta.x * sensitivity.x,
-            -0.1 * delta.y * sensitivity.y,
-        );
-    }
-
-    if keys.just_pressed(KeyCode::KeyE) {
-        let result = if *pan == Pan::Keys {
-            Pan::Mouse
-        } else {
-            Pan::Keys
-        };
-        next_pan.set(result);
-        println!("PanType: {result:?}");
-    }
-
     if keys.just_pressed(KeyCode::KeyP) {
         config.pin = !config.pin;
         println!(
@@ -249,12 +171,9 @@ fn update_camera(
                 false => "Static",
             }
         );
-    }
-
-    if config.pin {
-        if let Some(camera_pos) = rig.try_driver_mut::<Position>() {
-            for pos in trans.iter() {
-                camera_pos.position = pos.translation + Vec3::new(0., 1., 0.);
+        for mut rig in &mut rig_q {
+            if let Some(follow) = rig.try_driver_mut::<FollowEntity>() {
+                follow.enabled = config.pin;
             }
         }
     }
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use bevy_dolly::prelude::*;
+
+#[derive(Component)]
+struct MainCamera;
+
+// The rig of this example is described in assets/orbit.rig.ron
+// Run with `--features asset,bevy/file_watcher` and edit the file to see it hot-reload
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DollyPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_camera)
+        .add_systems(
+            PostUpdate,
+            Dolly::<MainCamera>::update_active_continuous.in_set(DollyApplySet),
+        )
+        .run();
+}
+
+/// set up a simple 3D scene
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+) {
+    // plane
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5., 5.))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+
+    let poly_dolly = asset_server.load(GltfAssetLabel::Scene(0).from_asset("poly_dolly.gltf"));
+
+    commands.spawn((SceneRoot(poly_dolly), Transform::from_xyz(0., 0.2, 0.)));
+
+    commands.spawn((
+        MainCamera,
+        RigHandle(asset_server.load("orbit.rig.ron")),
+        Camera3d::default(),
+        Transform::from_xyz(-2.0, 1., 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    // light
+    commands.spawn((PointLight::default(), Transform::from_xyz(4.0, 8.0, 4.0)));
+
+    info!("Use Z and X to orbit the sheep");
+}
+
+fn update_camera(keys: Res<ButtonInput<KeyCode>>, mut rig_q: Query<&mut Rig>) {
+    for mut rig in &mut rig_q {
+        if let Some(yaw_pitch) = rig.try_driver_mut::<YawPitch>() {
+            if keys.just_pressed(KeyCode::KeyZ) {
+                yaw_pitch.rotate_yaw_pitch(-90.0, 0.0);
+            }
+            if keys.just_pressed(KeyCode::KeyX) {
+                yaw_pitch.rotate_yaw_pitch(90.0, 0.0);
+            }
+        }
+    }
+}
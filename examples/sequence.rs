@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use bevy_dolly::prelude::*;
+
+#[derive(Component)]
+struct MainCamera;
+
+// The cutscene of this example is described in assets/intro.sequence.ron
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DollyPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (control_playback, log_markers))
+        .add_systems(
+            PostUpdate,
+            Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+        )
+        .run();
+}
+
+/// set up a simple 3D scene
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+) {
+    // plane
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(5., 5.))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+
+    let poly_dolly = asset_server.load(GltfAssetLabel::Scene(0).from_asset("poly_dolly.gltf"));
+
+    // Looked at by name from the sequence
+    commands.spawn((
+        Name::new("Sheep"),
+        SceneRoot(poly_dolly),
+        Transform::from_xyz(0., 0.2, 0.),
+    ));
+
+    commands.spawn((
+        MainCamera,
+        Camera3d::default(),
+        Rig::builder()
+            .with(Position::default())
+            .with(Rotation::default())
+            .with(Smooth::new_position_rotation(0.2, 0.2))
+            .build(),
+        SequencePlayer::new(asset_server.load("intro.sequence.ron")).with_looping(true),
+    ));
+
+    // light
+    commands.spawn((PointLight::default(), Transform::from_xyz(4.0, 8.0, 4.0)));
+
+    info!("Use Space to pause and R to restart the sequence");
+}
+
+fn control_playback(keys: Res<ButtonInput<KeyCode>>, mut players: Query<&mut SequencePlayer>) {
+    for mut player in &mut players {
+        if keys.just_pressed(KeyCode::Space) {
+            if player.is_playing() {
+                player.pause();
+            } else {
+                player.play();
+            }
+        }
+        if keys.just_pressed(KeyCode::KeyR) {
+            player.seek(0.);
+            player.play();
+        }
+    }
+}
+
+fn log_markers(mut markers: EventReader<SequenceMarkerReached>) {
+    for marker in markers.read() {
+        info!("Reached {}", marker.name);
+    }
+}
@@ -10,26 +10,25 @@ use bevy_dolly::prelude::*;
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, DollyPlugin))
         .add_systems(Startup, setup)
+        .add_systems(Update, (set_camera_viewports, update_cameras))
         .add_systems(
-            Update,
-            (
-                Dolly::<LeftCamera>::update_active,
-                Dolly::<RightCamera>::update_active,
-                set_camera_viewports,
-                update_camera_1,
-                update_camera_2,
-            ),
+            PostUpdate,
+            Dolly::<SplitCamera>::update_active.in_set(DollyApplySet),
         )
         .run();
 }
 
+/// A single marker shared by both rigs and cameras, the pairs are bound with `RigTarget`
 #[derive(Component)]
-struct LeftCamera;
+struct SplitCamera;
 
-#[derive(Component)]
-struct RightCamera;
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+enum Side {
+    Left,
+    Right,
+}
 
 /// set up a simple 3D scene
 fn setup(
@@ -67,14 +66,33 @@ fn setup(
     ));
 
     // camera
-    commands.spawn((
-        LeftCamera,
-        Camera3d::default(),
-        Transform::from_xyz(0.0, 200.0, -100.0).looking_at(Vec3::ZERO, Vec3::Y),
-    ));
+    let left_camera = commands
+        .spawn((
+            SplitCamera,
+            Side::Left,
+            Camera3d::default(),
+            Transform::from_xyz(0.0, 200.0, -100.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ))
+        .id();
+
+    // camera
+    let right_camera = commands
+        .spawn((
+            SplitCamera,
+            Side::Right,
+            Camera3d::default(),
+            Camera {
+                clear_color: ClearColorConfig::None,
+                order: 1,
+                ..default()
+            },
+            Transform::from_xyz(100.0, 100., 150.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ))
+        .id();
 
     commands.spawn((
-        LeftCamera,
+        SplitCamera,
+        RigTarget(left_camera),
         Rig::builder()
             .with(YawPitch::new().yaw_degrees(45.0).pitch_degrees(-30.0))
             .with(Smooth::new_rotation(1.5))
@@ -83,76 +101,54 @@ fn setup(
     ));
 
     commands.spawn((
-        RightCamera,
+        SplitCamera,
+        RigTarget(right_camera),
         Rig::builder()
             .with(YawPitch::new().yaw_degrees(45.0).pitch_degrees(-30.0))
             .with(Smooth::new_rotation(1.5))
             .with(Arm::new(Vec3::Z * 200.0))
             .build(),
     ));
-
-    // camera
-    commands.spawn((
-        Camera3d::default(),
-        Camera {
-            clear_color: ClearColorConfig::None,
-            order: 1,
-            ..default()
-        },
-        Transform::from_xyz(100.0, 100., 150.0).looking_at(Vec3::ZERO, Vec3::Y),
-        RightCamera,
-    ));
 }
 
 fn set_camera_viewports(
     windows: Query<(Entity, &Window), With<PrimaryWindow>>,
     mut resize_events: EventReader<WindowResized>,
-    mut left_camera: Query<&mut Camera, (With<LeftCamera>, Without<RightCamera>)>,
-    mut right_camera: Query<&mut Camera, With<RightCamera>>,
+    mut cameras: Query<(&mut Camera, &Side)>,
 ) {
     for resize_event in resize_events.read() {
         if let Ok((entity, window)) = windows.get_single() {
             if resize_event.window == entity {
-                let mut left_camera = left_camera.single_mut();
-                left_camera.viewport = Some(Viewport {
-                    physical_position: UVec2::new(0, 0),
-                    physical_size: UVec2::new(
-                        window.physical_width() / 2,
-                        window.physical_height(),
-                    ),
-                    depth: 0.0..1.0,
-                });
-
-                let mut right_camera = right_camera.single_mut();
-                right_camera.viewport = Some(Viewport {
-                    physical_position: UVec2::new(window.physical_width() / 2, 0),
-                    physical_size: UVec2::new(
-                        window.physical_width() / 2,
-                        window.physical_height(),
-                    ),
-                    depth: 0.0..1.0,
-                });
+                let half_width = window.physical_width() / 2;
+                for (mut camera, side) in &mut cameras {
+                    let x = match side {
+                        Side::Left => 0,
+                        Side::Right => half_width,
+                    };
+                    camera.viewport = Some(Viewport {
+                        physical_position: UVec2::new(x, 0),
+                        physical_size: UVec2::new(half_width, window.physical_height()),
+                        depth: 0.0..1.0,
+                    });
+                }
             }
         }
     }
 }
 
-fn update_camera_1(mut query: Query<&mut Rig, (With<LeftCamera>, Without<RightCamera>)>) {
-    let mut rig = query.single_mut();
-    let camera_driver = rig.driver_mut::<YawPitch>();
-
-    camera_driver.rotate_yaw_pitch(1.0, 0.0);
-}
-
-fn update_camera_2(
-    time: Res<Time>,
-    mut query: Query<&mut Rig, (With<RightCamera>, Without<LeftCamera>)>,
-) {
-    let mut rig = query.single_mut();
-    let camera_driver = rig.driver_mut::<YawPitch>();
-
-    camera_driver.rotate_yaw_pitch(-1.0, 0.0);
+fn update_cameras(time: Res<Time>, mut rigs: Query<(&mut Rig, &RigTarget)>, sides: Query<&Side>) {
+    for (mut rig, target) in &mut rigs {
+        match sides.get(target.0) {
+            Ok(Side::Left) => {
+                rig.driver_mut::<YawPitch>().rotate_yaw_pitch(1.0, 0.0);
+            }
+            Ok(Side::Right) => {
+                rig.driver_mut::<YawPitch>().rotate_yaw_pitch(-1.0, 0.0);
 
-    let a = rig.driver_mut::<Arm>();
-    a.offset = Vec3::Z * ((time.delta_secs() * 0.2).sin().cos().abs() * 400. - 200.);
+                let a = rig.driver_mut::<Arm>();
+                a.offset = Vec3::Z * ((time.delta_secs() * 0.2).sin().cos().abs() * 400. - 200.);
+            }
+            Err(_) => (),
+        }
+    }
 }
@@ -7,32 +7,33 @@ use bevy_dolly::prelude::*;
 #[derive(Component)]
 struct MainCamera;
 
-// In this example we are going to switch our look at target
-// All you need to do is set a LookAt driver target_entity
-// and its will track it
-
-#[derive(States, Default, Clone, Eq, PartialEq, Debug, Hash)]
-enum Camera {
-    #[default]
-    FollowPlayer,
-    FollowSheep,
-}
+// In this example we are going to switch between two rigs
+// Both rigs are always updated, the DollyBrain on the camera
+// blends to whichever has the highest RigPriority
+
+#[derive(Component)]
+struct PlayerRig;
+
+#[derive(Component)]
+struct SheepRig;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, DollyPosCtrl))
+        .add_plugins((DefaultPlugins, DollyPlugin, DollyPosCtrl))
         .add_systems(Startup, setup)
         .add_systems(
             Update,
             (
-                Dolly::<MainCamera>::update_active,
                 rotator_system,
                 switch_camera_rig,
-                follow_player.run_if(in_state(Camera::FollowPlayer)),
-                follow_sheep.run_if(in_state(Camera::FollowSheep)),
+                follow_player,
+                follow_sheep,
             ),
         )
-        .init_state::<Camera>()
+        .add_systems(
+            PostUpdate,
+            Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+        )
         .run();
 }
 
@@ -62,8 +63,7 @@ fn setup(
         },
     ));
 
-    commands.spawn((
-        MainCamera,
+    let follow_rig = || {
         Rig::builder()
             .with(Position::new(start_pos))
             .with(Rotation::new(Quat::IDENTITY))
@@ -75,11 +75,15 @@ fn setup(
                     .tracking_smoothness(1.25)
                     .tracking_predictive(true),
             )
-            .build(),
-    ));
+            .build()
+    };
+
+    commands.spawn((MainCamera, PlayerRig, RigPriority::new(1), follow_rig()));
+    commands.spawn((MainCamera, SheepRig, RigPriority::new(0), follow_rig()));
 
     commands.spawn((
         MainCamera,
+        DollyBrain::new(1.5),
         Camera3d::default(),
         Transform::from_xyz(-2.0, 1., 5.0).looking_at(Vec3::ZERO, Vec3::Y),
     ));
@@ -92,7 +96,10 @@ fn setup(
     info!("Press C to toggle between the default player and the sheep");
 }
 
-fn follow_player(query: Query<&Transform, With<DollyPosCtrlMove>>, mut q: Query<&mut Rig>) {
+fn follow_player(
+    query: Query<&Transform, With<DollyPosCtrlMove>>,
+    mut q: Query<&mut Rig, With<PlayerRig>>,
+) {
     if let Ok(p) = query.get_single() {
         let mut rig = q.single_mut();
         rig.driver_mut::<Position>().position = p.translation;
@@ -101,7 +108,10 @@ fn follow_player(query: Query<&Transform, With<DollyPosCtrlMove>>, mut q: Query<
     }
 }
 
-fn follow_sheep(query: Query<&Transform, With<Rotates>>, mut rig_q: Query<&mut Rig>) {
+fn follow_sheep(
+    query: Query<&Transform, With<Rotates>>,
+    mut rig_q: Query<&mut Rig, With<SheepRig>>,
+) {
     if let Ok(p) = query.get_single() {
         let mut rig = rig_q.single_mut();
         rig.driver_mut::<Position>().position = p.translation;
@@ -122,18 +132,14 @@ fn rotator_system(time: Res<Time>, mut query: Query<&mut Transform, With<Rotates
 }
 
 fn switch_camera_rig(
-    camera: Res<State<Camera>>,
-    mut next_camera: ResMut<NextState<Camera>>,
+    mut sheep: Query<&mut RigPriority, With<SheepRig>>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
 ) {
     if keyboard_input.just_pressed(KeyCode::KeyC) {
-        let result = if *camera == Camera::FollowPlayer {
-            Camera::FollowSheep
-        } else {
-            Camera::FollowPlayer
-        };
-
-        println!("{result:?}");
-        next_camera.set(result);
+        // Raising the sheep rig above the player rig makes the brain blend to it
+        let mut priority = sheep.single_mut();
+        priority.priority = if priority.priority > 1 { 0 } else { 2 };
+
+        println!("Sheep priority: {}", priority.priority);
     }
 }
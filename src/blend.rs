@@ -0,0 +1,244 @@
+use crate::prelude::*;
+use bevy::{
+    math::curve::{Curve, EaseFunction, EasingCurve},
+    prelude::*,
+};
+
+/// Blends a camera from the output of one [`Rig`] to another, instead of cutting to it.
+///
+/// Insert it on the camera entity. Both rigs keep being evaluated by their [`Dolly`] systems,
+/// while the blend overrides the camera's transform and, if given a target projection,
+/// lerps the field of view or orthographic scale. When the blend finishes the camera is bound to
+/// the rig it blended to with a [`RigTarget`], other rigs bound to it are unbound,
+/// and the component is removed. [`RigBlendStarted`] and [`RigBlendFinished`] are sent
+/// along the way.
+///
+/// Inserting a new blend on a camera that is still blending interrupts the current one,
+/// the new blend starts from wherever the camera is at that moment.
+/// The rig blended from should live on its own entity, as a rig on the camera entity keeps
+/// driving it after the blend.
+/// ```rust
+/// # use bevy::{math::curve::EaseFunction, prelude::*};
+/// # use bevy_dolly::prelude::*;
+/// # fn cut_to(mut commands: Commands, camera: Entity, rig: Entity) {
+/// commands
+///     .entity(camera)
+///     .insert(RigBlend::to(rig, 1.5).with_ease(EaseFunction::CubicInOut));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct RigBlend {
+    /// Rig to blend from, `None` starts from the camera's pose when the blend begins
+    pub from: Option<Entity>,
+    /// Rig to blend to
+    pub to: Entity,
+    /// Length of the blend in seconds
+    pub duration: f32,
+    pub ease: EaseFunction,
+    /// Projection at the end of the blend, `None` leaves the projection untouched
+    pub projection: Option<Projection>,
+    elapsed: f32,
+    start: Option<BlendStart>,
+}
+
+/// The camera's state when a blend begins
+#[derive(Debug, Clone)]
+struct BlendStart {
+    pose: Transform,
+    projection: Option<Projection>,
+}
+
+impl RigBlend {
+    /// Blends from the camera's current pose to a rig
+    pub fn to(to: Entity, duration: f32) -> Self {
+        Self {
+            from: None,
+            to,
+            duration,
+            ease: EaseFunction::Linear,
+            projection: None,
+            elapsed: 0.,
+            start: None,
+        }
+    }
+
+    /// Blends from the output of one rig to another
+    pub fn between(from: Entity, to: Entity, duration: f32) -> Self {
+        Self {
+            from: Some(from),
+            ..Self::to(to, duration)
+        }
+    }
+
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    pub fn with_projection(mut self, projection: impl Into<Projection>) -> Self {
+        self.projection = Some(projection.into());
+        self
+    }
+
+    /// Progress of the blend from 0 to 1, before easing
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0. {
+            (self.elapsed / self.duration).clamp(0., 1.)
+        } else {
+            1.
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.
+    }
+}
+
+/// Sent when a camera starts a [`RigBlend`]
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RigBlendStarted {
+    pub camera: Entity,
+    pub to: Entity,
+}
+
+/// Sent when a camera completes a [`RigBlend`], or a new blend interrupts it
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RigBlendFinished {
+    pub camera: Entity,
+    pub to: Entity,
+    pub interrupted: bool,
+}
+
+/// The rig a camera is currently blending to and its blended pose,
+/// so an interrupting blend starts from there instead of the pose written by the rigs
+#[derive(Component)]
+pub(crate) struct ActiveRigBlend {
+    to: Entity,
+    pose: Transform,
+}
+
+/// Interpolates the field of view or orthographic scale, does nothing if the kinds differ
+fn blend_projection(projection: &mut Projection, from: &Projection, to: &Projection, t: f32) {
+    match (projection, from, to) {
+        (
+            Projection::Perspective(p),
+            Projection::Perspective(from),
+            Projection::Perspective(to),
+        ) => {
+            p.fov = from.fov.lerp(to.fov, t);
+        }
+        (
+            Projection::Orthographic(o),
+            Projection::Orthographic(from),
+            Projection::Orthographic(to),
+        ) => {
+            o.scale = from.scale.lerp(to.scale, t);
+        }
+        _ => (),
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_rig_blends(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<(
+        Entity,
+        &mut RigBlend,
+        &mut Transform,
+        Option<&mut Projection>,
+        Option<&mut OrthographicProjection>,
+        Option<&ActiveRigBlend>,
+    )>,
+    rigs: Query<(Entity, &Rig, Option<&RigTarget>)>,
+    mut started: EventWriter<RigBlendStarted>,
+    mut finished: EventWriter<RigBlendFinished>,
+) {
+    for (camera, mut blend, mut transform, mut projection, mut orthographic, active) in &mut cameras
+    {
+        let Ok((_, to, _)) = rigs.get(blend.to) else {
+            warn!(
+                "RigBlend on {camera} targets {}, which has no Rig",
+                blend.to
+            );
+            commands
+                .entity(camera)
+                .remove::<(RigBlend, ActiveRigBlend)>();
+            continue;
+        };
+
+        if blend.start.is_none() {
+            let mut pose = *transform;
+            if let Some(active) = active {
+                pose = active.pose;
+                finished.send(RigBlendFinished {
+                    camera,
+                    to: active.to,
+                    interrupted: true,
+                });
+            }
+            blend.start = Some(BlendStart {
+                pose,
+                projection: match (&projection, &orthographic) {
+                    (Some(p), _) => Some((**p).clone()),
+                    (None, Some(o)) => Some(Projection::Orthographic((**o).clone())),
+                    (None, None) => None,
+                },
+            });
+            started.send(RigBlendStarted {
+                camera,
+                to: blend.to,
+            });
+        }
+
+        blend.elapsed += time.delta_secs();
+        let t = EasingCurve::new(0., 1., blend.ease).sample_clamped(blend.progress());
+
+        let from = match blend.from.and_then(|from| rigs.get(from).ok()) {
+            Some((_, from, _)) => from.final_transform,
+            None => blend.start.as_ref().map_or(*transform, |start| start.pose),
+        };
+        let to_pose = to.final_transform;
+        transform.translation = from.translation.lerp(to_pose.translation, t);
+        transform.rotation = from.rotation.slerp(to_pose.rotation, t);
+
+        if let (Some(target), Some(start)) = (&blend.projection, &blend.start) {
+            if let Some(from) = &start.projection {
+                if let Some(projection) = projection.as_deref_mut() {
+                    blend_projection(projection, from, target, t);
+                } else if let (Some(orthographic), Projection::Orthographic(from)) =
+                    (orthographic.as_deref_mut(), from)
+                {
+                    if let Projection::Orthographic(target) = target {
+                        orthographic.scale = from.scale.lerp(target.scale, t);
+                    }
+                }
+            }
+        }
+
+        commands.entity(camera).insert(ActiveRigBlend {
+            to: blend.to,
+            pose: *transform,
+        });
+
+        if blend.is_finished() {
+            // Any rig blended away from, including those of interrupted blends, lets go of the camera
+            for (rig, _, target) in &rigs {
+                if rig != blend.to && target.is_some_and(|t| t.0 == camera) {
+                    commands.entity(rig).remove::<RigTarget>();
+                }
+            }
+            if blend.to != camera {
+                commands.entity(blend.to).insert(RigTarget(camera));
+            }
+            commands
+                .entity(camera)
+                .remove::<(RigBlend, ActiveRigBlend)>();
+            finished.send(RigBlendFinished {
+                camera,
+                to: blend.to,
+                interrupted: false,
+            });
+        }
+    }
+}
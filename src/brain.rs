@@ -0,0 +1,120 @@
+use crate::prelude::*;
+use bevy::{math::curve::EaseFunction, prelude::*};
+
+/// Makes a [`Rig`] a virtual camera that a [`DollyBrain`] can pick.
+///
+/// The rig should live on its own entity, the brain binds it to the camera with a
+/// [`RigTarget`] once it is live. Until then it doesn't drive any camera.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RigPriority {
+    /// The enabled rig with the highest priority goes live, ties keep the current one
+    pub priority: i32,
+    pub enabled: bool,
+    /// Only the brain on this camera considers the rig, `None` makes it available to every brain
+    pub camera: Option<Entity>,
+}
+
+impl RigPriority {
+    pub fn new(priority: i32) -> Self {
+        Self {
+            priority,
+            enabled: true,
+            camera: None,
+        }
+    }
+
+    pub fn for_camera(mut self, camera: Entity) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+}
+
+/// Drives a camera from whichever [`RigPriority`] rig currently has the highest priority.
+///
+/// Insert it on the camera entity. The first live rig is cut to, after that every change of
+/// live rig blends to the new one with a [`RigBlend`].
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((MainCamera, Camera3d::default(), DollyBrain::new(1.5)));
+/// commands.spawn((MainCamera, RigPriority::new(10), Rig::builder().build()));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct DollyBrain {
+    /// Length of the blend to a new live rig in seconds, 0 cuts to it
+    pub blend_duration: f32,
+    pub ease: EaseFunction,
+    live: Option<Entity>,
+}
+
+impl Default for DollyBrain {
+    fn default() -> Self {
+        Self::new(1.)
+    }
+}
+
+impl DollyBrain {
+    pub fn new(blend_duration: f32) -> Self {
+        Self {
+            blend_duration,
+            ease: EaseFunction::CubicInOut,
+            live: None,
+        }
+    }
+
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// The rig the camera is driven by, or blending to
+    pub fn live(&self) -> Option<Entity> {
+        self.live
+    }
+}
+
+pub(crate) fn update_brains(
+    mut commands: Commands,
+    mut brains: Query<(Entity, &mut DollyBrain, Has<RigBlend>)>,
+    mut rigs: Query<(Entity, &RigPriority, &mut Rig)>,
+) {
+    for (camera, mut brain, blending) in &mut brains {
+        let best = rigs
+            .iter()
+            .filter(|(_, p, _)| p.enabled && p.camera.is_none_or(|c| c == camera))
+            .max_by_key(|(e, p, _)| (p.priority, Some(*e) == brain.live))
+            .map(|(e, _, _)| e);
+
+        // Without any enabled rig the camera stays with the last live one
+        let Some(next) = best else {
+            continue;
+        };
+        if brain.live == Some(next) {
+            continue;
+        }
+
+        match brain.live {
+            None => {
+                commands.entity(next).insert(RigTarget(camera));
+                // Rigs updated on change would only reach the camera once they move
+                if let Ok((_, _, mut rig)) = rigs.get_mut(next) {
+                    rig.set_changed();
+                }
+            }
+            Some(live) => {
+                // An interrupted blend continues from where the camera is
+                let blend = if blending {
+                    RigBlend::to(next, brain.blend_duration)
+                } else {
+                    RigBlend::between(live, next, brain.blend_duration)
+                };
+                commands.entity(camera).insert(blend.with_ease(brain.ease));
+            }
+        }
+        brain.live = Some(next);
+    }
+}
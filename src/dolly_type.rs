@@ -1,26 +1,165 @@
-use crate::dolly::prelude::*;
-use bevy::prelude::{Component, Deref, DerefMut};
+use crate::{
+    dolly::prelude::*,
+    driver_state::DriverState,
+    lens::{Lens, LensDriver, LensDriverTraits, LensUpdateParams},
+    timestep::Substeps,
+};
+use bevy::prelude::{Component, Deref, DerefMut, Entity, Reflect, ReflectComponent, Transform};
 
-#[derive(Component, Deref, DerefMut)]
-pub struct Rig(CameraRig);
+#[derive(Component, Deref, DerefMut, Reflect)]
+#[reflect(Component, from_reflect = false)]
+pub struct Rig {
+    #[deref]
+    #[reflect(ignore)]
+    rig: CameraRig,
+    /// Reflected copy of the drivers, see [`DriverState`]
+    pub driver_states: Vec<DriverState>,
+    #[reflect(ignore)]
+    synced_states: Vec<DriverState>,
+    /// Drivers of the camera's projection, evaluated after the drivers of the transform
+    #[reflect(ignore)]
+    pub lens_drivers: Vec<Box<dyn LensDriverTraits>>,
+    /// Output of the lens drivers
+    pub final_lens: Lens,
+    /// Splits long frames into several updates, see [`RigBuilder::with_substeps`]
+    pub substeps: Option<Substeps>,
+}
 
 impl Rig {
     /// Use this to make a new rig
     pub fn builder() -> RigBuilder {
-        RigBuilder(CameraRig::builder())
+        RigBuilder {
+            rig: CameraRig::builder(),
+            lens_drivers: Vec::new(),
+            substeps: None,
+        }
+    }
+
+    fn new(rig: CameraRig, lens_drivers: Vec<Box<dyn LensDriverTraits>>) -> Self {
+        let driver_states = DriverState::capture_all(&rig.drivers);
+        let mut rig = Self {
+            rig,
+            synced_states: driver_states.clone(),
+            driver_states,
+            lens_drivers,
+            final_lens: Lens::default(),
+            substeps: None,
+        };
+        rig.update_lens(0.);
+        rig
+    }
+
+    /// Runs the drivers and then the lens drivers, returns the final transform
+    pub fn update(&mut self, delta_time_seconds: f32) -> Transform {
+        let (steps, step) = self.substeps.map_or((1, delta_time_seconds), |substeps| {
+            substeps.split(delta_time_seconds)
+        });
+        for _ in 0..steps {
+            self.rig.update(step);
+            self.update_lens(step);
+        }
+        self.rig.final_transform
+    }
+
+    fn update_lens(&mut self, delta_time_seconds: f32) {
+        let transform = self.rig.final_transform;
+        self.final_lens = self
+            .lens_drivers
+            .iter_mut()
+            .fold(Lens::default(), |parent, driver| {
+                driver.update(LensUpdateParams {
+                    parent: &parent,
+                    transform: &transform,
+                    delta_time_seconds,
+                })
+            });
+    }
+
+    pub fn lens_driver<T: LensDriver>(&self) -> &T {
+        self.try_lens_driver::<T>()
+            .unwrap_or_else(|| panic!("No {} lens driver in rig", std::any::type_name::<T>()))
+    }
+
+    pub fn lens_driver_mut<T: LensDriver>(&mut self) -> &mut T {
+        self.try_lens_driver_mut::<T>()
+            .unwrap_or_else(|| panic!("No {} lens driver in rig", std::any::type_name::<T>()))
+    }
+
+    pub fn try_lens_driver<T: LensDriver>(&self) -> Option<&T> {
+        self.lens_drivers
+            .iter()
+            .find_map(|driver| driver.as_any().downcast_ref::<T>())
+    }
+
+    pub fn try_lens_driver_mut<T: LensDriver>(&mut self) -> Option<&mut T> {
+        self.lens_drivers
+            .iter_mut()
+            .find_map(|driver| driver.as_any_mut().downcast_mut::<T>())
+    }
+
+    /// Whether [`Rig::driver_states`] was edited since the last capture
+    pub fn driver_states_edited(&self) -> bool {
+        self.driver_states != self.synced_states
+    }
+
+    /// Writes [`Rig::driver_states`] to the drivers
+    pub fn apply_driver_states(&mut self) {
+        DriverState::apply_all(&self.driver_states, &mut self.rig.drivers);
+        self.synced_states.clone_from(&self.driver_states);
+    }
+
+    /// Copies the current state of the drivers into [`Rig::driver_states`]
+    pub fn capture_driver_states(&mut self) {
+        self.driver_states = DriverState::capture_all(&self.rig.drivers);
+        self.synced_states.clone_from(&self.driver_states);
     }
 }
 
-pub struct RigBuilder(CameraRigBuilder);
+/// Binds a [`Rig`] to the entity it drives.
+///
+/// A rig without a `RigTarget` drives its own entity when that entity is one of the
+/// marked cameras, and otherwise every marked camera that isn't bound to another rig.
+/// A rig with a [`RigPriority`](crate::brain::RigPriority) drives nothing until a
+/// [`DollyBrain`](crate::brain::DollyBrain) binds it.
+/// The target still needs the marker component used by the [`Dolly`](crate::system::Dolly)
+/// system, so a single marker can be shared by any number of rig/camera pairs.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Deref)]
+pub struct RigTarget(pub Entity);
+
+pub struct RigBuilder {
+    rig: CameraRigBuilder,
+    lens_drivers: Vec<Box<dyn LensDriverTraits>>,
+    substeps: Option<Substeps>,
+}
 
 impl RigBuilder {
     pub fn with(mut self, driver: impl RigDriverTraits) -> Self {
-        let dolly_crb = self.0.with(driver);
-        self.0 = dolly_crb;
+        let dolly_crb = self.rig.with(driver);
+        self.rig = dolly_crb;
+        self
+    }
+
+    /// Adds a driver of the camera's projection, ie. [`FovZoom`](crate::lens::FovZoom)
+    pub fn with_lens(mut self, driver: impl LensDriver) -> Self {
+        self.lens_drivers.push(Box::new(driver));
+        self
+    }
+
+    /// Updates the rig in steps of at most `max_step_seconds`, up to `max_substeps` per frame
+    pub fn with_substeps(mut self, max_step_seconds: f32, max_substeps: u32) -> Self {
+        self.substeps = Some(Substeps::new(max_step_seconds, max_substeps));
+        self
+    }
+
+    /// Adds a driver described by a [`DriverState`]
+    pub fn with_state(mut self, state: &DriverState) -> Self {
+        self.rig = state.add_to_builder(self.rig);
         self
     }
 
     pub fn build(self) -> Rig {
-        Rig(self.0.build())
+        let mut rig = Rig::new(self.rig.build(), self.lens_drivers);
+        rig.substeps = self.substeps;
+        rig
     }
 }
@@ -0,0 +1,220 @@
+use crate::dolly::prelude::*;
+#[cfg(feature = "drivers")]
+use crate::drivers::{follow::MovableLookAt, fpv::Fpv};
+use bevy::prelude::*;
+#[cfg(feature = "asset")]
+use serde::{Deserialize, Serialize};
+
+/// Reflected copy of a single driver in a [`Rig`](crate::dolly_type::Rig).
+///
+/// Dolly drivers aren't [`Reflect`] themselves, so each rig keeps a list of these
+/// next to its drivers. Editing a state, ie. from bevy-inspector-egui or the remote protocol,
+/// is written back to the matching driver by [`DollyPlugin`](crate::system::DollyPlugin).
+/// A list of states also describes a whole rig, see [`RigBuilder::with_state`](crate::dolly_type::RigBuilder::with_state).
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(no_field_bounds)]
+#[cfg_attr(feature = "asset", derive(Serialize, Deserialize))]
+pub enum DriverState {
+    Position {
+        position: Vec3,
+    },
+    Rotation {
+        rotation: Quat,
+    },
+    YawPitch {
+        yaw_degrees: f32,
+        pitch_degrees: f32,
+    },
+    Smooth {
+        position_smoothness: f32,
+        rotation_smoothness: f32,
+    },
+    Arm {
+        offset: Vec3,
+    },
+    LookAt {
+        target: Vec3,
+        smoothness: f32,
+    },
+    #[cfg(feature = "drivers")]
+    Fpv {
+        drivers: Vec<DriverState>,
+    },
+    #[cfg(feature = "drivers")]
+    MovableLookAt {
+        drivers: Vec<DriverState>,
+    },
+    /// A driver without reflection support, edits to it are ignored
+    Opaque,
+}
+
+impl DriverState {
+    /// Reads the state of a driver
+    pub fn capture(driver: &dyn RigDriverTraits) -> Self {
+        let any = driver.as_any();
+        if let Some(d) = any.downcast_ref::<Position>() {
+            DriverState::Position {
+                position: d.position,
+            }
+        } else if let Some(d) = any.downcast_ref::<Rotation>() {
+            DriverState::Rotation {
+                rotation: d.rotation,
+            }
+        } else if let Some(d) = any.downcast_ref::<YawPitch>() {
+            DriverState::YawPitch {
+                yaw_degrees: d.yaw_degrees,
+                pitch_degrees: d.pitch_degrees,
+            }
+        } else if let Some(d) = any.downcast_ref::<Smooth>() {
+            DriverState::Smooth {
+                position_smoothness: d.position_smoothness,
+                rotation_smoothness: d.rotation_smoothness,
+            }
+        } else if let Some(d) = any.downcast_ref::<Arm>() {
+            DriverState::Arm { offset: d.offset }
+        } else if let Some(d) = any.downcast_ref::<LookAt>() {
+            DriverState::LookAt {
+                target: d.target,
+                smoothness: d.smoothness,
+            }
+        } else {
+            Self::capture_nested(driver)
+        }
+    }
+
+    #[cfg(feature = "drivers")]
+    fn capture_nested(driver: &dyn RigDriverTraits) -> Self {
+        let any = driver.as_any();
+        if let Some(d) = any.downcast_ref::<Fpv>() {
+            DriverState::Fpv {
+                drivers: Self::capture_all(&d.drivers),
+            }
+        } else if let Some(d) = any.downcast_ref::<MovableLookAt>() {
+            DriverState::MovableLookAt {
+                drivers: Self::capture_all(&d.drivers),
+            }
+        } else {
+            DriverState::Opaque
+        }
+    }
+
+    #[cfg(not(feature = "drivers"))]
+    fn capture_nested(_: &dyn RigDriverTraits) -> Self {
+        DriverState::Opaque
+    }
+
+    /// Reads the state of every driver in a rig, in order
+    pub fn capture_all(drivers: &[Box<dyn RigDriverTraits>]) -> Vec<Self> {
+        drivers.iter().map(|d| Self::capture(d.as_ref())).collect()
+    }
+
+    /// Adds a new driver in this state to a rig, [`DriverState::Opaque`] can't be built and is skipped
+    pub fn add_to_builder(&self, builder: CameraRigBuilder) -> CameraRigBuilder {
+        match self {
+            DriverState::Position { position } => builder.with(Position::new(*position)),
+            DriverState::Rotation { rotation } => builder.with(Rotation::new(*rotation)),
+            DriverState::YawPitch {
+                yaw_degrees,
+                pitch_degrees,
+            } => builder.with(
+                YawPitch::new()
+                    .yaw_degrees(*yaw_degrees)
+                    .pitch_degrees(*pitch_degrees),
+            ),
+            DriverState::Smooth {
+                position_smoothness,
+                rotation_smoothness,
+            } => builder.with(Smooth::new_position_rotation(
+                *position_smoothness,
+                *rotation_smoothness,
+            )),
+            DriverState::Arm { offset } => builder.with(Arm::new(*offset)),
+            DriverState::LookAt { target, smoothness } => {
+                builder.with(LookAt::new(*target).tracking_smoothness(*smoothness))
+            }
+            #[cfg(feature = "drivers")]
+            DriverState::Fpv { drivers } => builder.with(Fpv::from(Self::build_all(drivers))),
+            #[cfg(feature = "drivers")]
+            DriverState::MovableLookAt { drivers } => {
+                builder.with(MovableLookAt::from(Self::build_all(drivers)))
+            }
+            DriverState::Opaque => builder,
+        }
+    }
+
+    /// Builds a rig with a driver for each state
+    pub fn build_all(states: &[Self]) -> CameraRig {
+        states
+            .iter()
+            .fold(CameraRig::builder(), |builder, state| {
+                state.add_to_builder(builder)
+            })
+            .build()
+    }
+
+    /// Writes this state to a driver, does nothing if the driver is of another type
+    pub fn apply(&self, driver: &mut dyn RigDriverTraits) {
+        let any = driver.as_any_mut();
+        match self {
+            DriverState::Position { position } => {
+                if let Some(d) = any.downcast_mut::<Position>() {
+                    d.position = *position;
+                }
+            }
+            DriverState::Rotation { rotation } => {
+                if let Some(d) = any.downcast_mut::<Rotation>() {
+                    d.rotation = *rotation;
+                }
+            }
+            DriverState::YawPitch {
+                yaw_degrees,
+                pitch_degrees,
+            } => {
+                if let Some(d) = any.downcast_mut::<YawPitch>() {
+                    d.yaw_degrees = *yaw_degrees;
+                    d.pitch_degrees = *pitch_degrees;
+                }
+            }
+            DriverState::Smooth {
+                position_smoothness,
+                rotation_smoothness,
+            } => {
+                if let Some(d) = any.downcast_mut::<Smooth>() {
+                    d.position_smoothness = *position_smoothness;
+                    d.rotation_smoothness = *rotation_smoothness;
+                }
+            }
+            DriverState::Arm { offset } => {
+                if let Some(d) = any.downcast_mut::<Arm>() {
+                    d.offset = *offset;
+                }
+            }
+            DriverState::LookAt { target, smoothness } => {
+                if let Some(d) = any.downcast_mut::<LookAt>() {
+                    d.target = *target;
+                    d.smoothness = *smoothness;
+                }
+            }
+            #[cfg(feature = "drivers")]
+            DriverState::Fpv { drivers } => {
+                if let Some(d) = any.downcast_mut::<Fpv>() {
+                    Self::apply_all(drivers, &mut d.drivers);
+                }
+            }
+            #[cfg(feature = "drivers")]
+            DriverState::MovableLookAt { drivers } => {
+                if let Some(d) = any.downcast_mut::<MovableLookAt>() {
+                    Self::apply_all(drivers, &mut d.drivers);
+                }
+            }
+            DriverState::Opaque => (),
+        }
+    }
+
+    /// Writes the states to the drivers at the same index
+    pub fn apply_all(states: &[Self], drivers: &mut [Box<dyn RigDriverTraits>]) {
+        for (state, driver) in states.iter().zip(drivers.iter_mut()) {
+            state.apply(driver.as_mut());
+        }
+    }
+}
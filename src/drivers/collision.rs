@@ -0,0 +1,361 @@
+use std::marker::PhantomData;
+
+use crate::{prelude::*, rig_2d::smoothing_t, system::RigCameras};
+use bevy::{
+    ecs::system::{StaticSystemParam, SystemParam, SystemParamItem},
+    prelude::*,
+    render::primitives::Aabb,
+};
+
+/// Finds the geometry between the pivot of a [`CollisionArm`] and the camera.
+///
+/// The crate only comes with [`AabbCollisionBackend`]. To collide against the colliders of a
+/// physics crate, implement it on top of the crate's spatial queries, ie. with avian's
+/// `SpatialQuery` as the `Param` and a shape cast in `cast`. A backend for a flat ground:
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// struct GroundCollisionBackend;
+///
+/// impl CameraCollisionBackend for GroundCollisionBackend {
+///     type Param = ();
+///
+///     fn cast(
+///         _: &(),
+///         origin: Vec3,
+///         direction: Dir3,
+///         max_distance: f32,
+///         radius: f32,
+///         _ignore: &[Entity],
+///     ) -> Option<CameraHit> {
+///         // Where the sphere touches the ground at a height of 0
+///         let distance = (origin.y - radius) / -direction.y;
+///         (direction.y < 0. && distance <= max_distance).then_some(CameraHit {
+///             entity: Entity::PLACEHOLDER,
+///             distance,
+///         })
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(DollyCollisionPlugin::<GroundCollisionBackend>::default());
+/// ```
+pub trait CameraCollisionBackend: Send + Sync + 'static {
+    /// Whatever the backend reads from the world, ie. a query or a physics crate's spatial query
+    type Param: SystemParam + 'static;
+
+    /// Casts a sphere of `radius` from `origin` along `direction`, returns the first hit within
+    /// `max_distance`. Hits with the `ignore`d entities don't count.
+    fn cast(
+        param: &SystemParamItem<Self::Param>,
+        origin: Vec3,
+        direction: Dir3,
+        max_distance: f32,
+        radius: f32,
+        ignore: &[Entity],
+    ) -> Option<CameraHit>;
+
+    /// Every hit within `max_distance`, closest first.
+    /// Casts again past each hit by default, backends with a cheaper way can override it.
+    fn cast_all(
+        param: &SystemParamItem<Self::Param>,
+        origin: Vec3,
+        direction: Dir3,
+        max_distance: f32,
+        radius: f32,
+        ignore: &[Entity],
+    ) -> Vec<CameraHit> {
+        const MAX_HITS: usize = 16;
+        let mut ignore = ignore.to_vec();
+        let mut hits = Vec::new();
+        while hits.len() < MAX_HITS {
+            let Some(hit) = Self::cast(param, origin, direction, max_distance, radius, &ignore)
+            else {
+                break;
+            };
+            ignore.push(hit.entity);
+            hits.push(hit);
+        }
+        hits
+    }
+}
+
+/// Something a [`CameraCollisionBackend`] cast ran into
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraHit {
+    pub entity: Entity,
+    /// Distance from the origin of the cast
+    pub distance: f32,
+}
+
+/// Collides against the [`Aabb`] of every mesh.
+///
+/// Boxes are oriented by the entity's [`GlobalTransform`], which isn't propagated yet when the
+/// arm is resolved, so moving geometry is a frame behind. Boxes the pivot starts inside of are
+/// ignored, so the character the camera follows doesn't block it.
+pub struct AabbCollisionBackend;
+
+impl CameraCollisionBackend for AabbCollisionBackend {
+    type Param =
+        Query<'static, 'static, (Entity, &'static Aabb, &'static GlobalTransform), Without<Camera>>;
+
+    fn cast(
+        boxes: &SystemParamItem<Self::Param>,
+        origin: Vec3,
+        direction: Dir3,
+        max_distance: f32,
+        radius: f32,
+        ignore: &[Entity],
+    ) -> Option<CameraHit> {
+        boxes
+            .iter()
+            .filter(|(entity, ..)| !ignore.contains(entity))
+            .filter_map(|(entity, aabb, transform)| {
+                let to_local = transform.affine().inverse();
+                // Inflating the box by the radius is close enough to casting a sphere
+                let padding =
+                    Vec3::splat(radius) / transform.scale().abs().max(Vec3::splat(f32::EPSILON));
+                let min = Vec3::from(aabb.min()) - padding;
+                let max = Vec3::from(aabb.max()) + padding;
+                let local_origin = to_local.transform_point3(origin);
+                if local_origin.cmpge(min).all() && local_origin.cmple(max).all() {
+                    return None;
+                }
+                let local_ray = to_local.transform_vector3(direction * max_distance);
+                ray_box(local_origin, local_ray, min, max).map(|t| CameraHit {
+                    entity,
+                    distance: t * max_distance,
+                })
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+}
+
+/// Fraction of `ray` where it enters the box, if it does
+fn ray_box(origin: Vec3, ray: Vec3, min: Vec3, max: Vec3) -> Option<f32> {
+    let inverse = ray.recip();
+    let (t1, t2) = ((min - origin) * inverse, (max - origin) * inverse);
+    let near = t1.min(t2).max_element();
+    let far = t1.max(t2).min_element();
+    (near <= far && (0. ..=1.).contains(&near)).then_some(near)
+}
+
+/// Like [`Arm`], but shortens the arm when geometry lies between the pivot and the camera.
+///
+/// The pivot is the output of the previous drivers, the arm points along their rotation.
+/// Collisions are found by the backend of a [`DollyCollisionPlugin`] after the rig is applied,
+/// which moves the camera in front of the hit. Drivers after the arm see the distance of the
+/// previous frame, [`MovableLookAt::with_collision`] swaps its [`Arm`] for one.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn rig(player: Entity) -> Rig {
+/// Rig::builder()
+///     .with(FollowEntity::new(player).with_offset(Vec3::Y))
+///     .with(YawPitch::new())
+///     .with(CollisionArm::new(Vec3::Z * 6.).with_min_distance(0.5))
+///     .build()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollisionArm {
+    pub offset: Vec3,
+    /// The arm never gets shorter than this, even when the pivot is boxed in
+    pub min_distance: f32,
+    /// Radius of the sphere cast along the arm, keeps the near plane out of walls
+    pub radius: f32,
+    /// Smoothness of shortening the arm, 0 snaps in front of the hit so nothing clips
+    pub pull_in_smoothness: f32,
+    /// Smoothness of growing back once the arm is clear
+    pub push_out_smoothness: f32,
+    /// Entities the arm passes through, ie. the followed character's colliders
+    pub ignore: Vec<Entity>,
+    distance: Option<f32>,
+    pivot: Vec3,
+    arm: Vec3,
+    output: Vec3,
+}
+
+impl CollisionArm {
+    pub fn new(offset: Vec3) -> Self {
+        Self {
+            offset,
+            min_distance: 0.,
+            radius: 0.2,
+            pull_in_smoothness: 0.,
+            push_out_smoothness: 1.,
+            ignore: Vec::new(),
+            distance: None,
+            pivot: Vec3::ZERO,
+            arm: offset,
+            output: offset,
+        }
+    }
+
+    pub fn with_min_distance(mut self, min_distance: f32) -> Self {
+        self.min_distance = min_distance;
+        self
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn with_smoothness(mut self, pull_in: f32, push_out: f32) -> Self {
+        self.pull_in_smoothness = pull_in;
+        self.push_out_smoothness = push_out;
+        self
+    }
+
+    pub fn with_ignored(mut self, entity: Entity) -> Self {
+        self.ignore.push(entity);
+        self
+    }
+
+    /// Current length of the arm
+    pub fn distance(&self) -> f32 {
+        self.distance.unwrap_or(self.offset.length())
+    }
+
+    /// Casts along the arm and smooths towards the free length,
+    /// returns how far the camera has to move since the rig was evaluated
+    fn collide<B: CameraCollisionBackend>(
+        &mut self,
+        backend: &SystemParamItem<B::Param>,
+        ignore: &[Entity],
+        delta_time_seconds: f32,
+    ) -> Vec3 {
+        let length = self.arm.length();
+        let Ok(direction) = Dir3::new(self.arm) else {
+            return Vec3::ZERO;
+        };
+        let mut ignored = self.ignore.clone();
+        ignored.extend_from_slice(ignore);
+        let free = B::cast(
+            backend,
+            self.pivot,
+            direction,
+            length,
+            self.radius,
+            &ignored,
+        )
+        .map_or(length, |hit| hit.distance)
+        .max(self.min_distance)
+        .min(length);
+
+        let current = self.distance.unwrap_or(length).min(length);
+        let smoothness = if free < current {
+            self.pull_in_smoothness
+        } else {
+            self.push_out_smoothness
+        };
+        let distance = current.lerp(free, smoothing_t(smoothness, delta_time_seconds));
+        self.distance = Some(distance);
+
+        let output = self.pivot + direction * distance;
+        let correction = output - self.output;
+        self.output = output;
+        correction
+    }
+}
+
+impl RigDriver for CollisionArm {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        self.pivot = params.parent.translation;
+        self.arm = params.parent.rotation * self.offset;
+        let length = self.arm.length();
+        let distance = self.distance.unwrap_or(length).min(length);
+        self.output = self.pivot + self.arm.normalize_or_zero() * distance;
+        Transform {
+            translation: self.output,
+            rotation: params.parent.rotation,
+            scale: Vec3::ONE,
+        }
+    }
+}
+
+impl MovableLookAt {
+    /// Swaps the [`Arm`] for a [`CollisionArm`] with the same offset
+    pub fn with_collision(mut self, min_distance: f32) -> Self {
+        for driver in self.drivers.iter_mut() {
+            if let Some(arm) = driver.as_mut().as_any_mut().downcast_mut::<Arm>() {
+                *driver = Box::new(CollisionArm::new(arm.offset).with_min_distance(min_distance));
+            }
+        }
+        self
+    }
+}
+
+/// Moves the cameras of rigs with a [`CollisionArm`] in front of the geometry found by `B`.
+///
+/// Adds [`DollyPlugin`] if it isn't yet. Add a single backend per app.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # let mut app = App::new();
+/// app.add_plugins(DollyCollisionPlugin::<AabbCollisionBackend>::default());
+/// ```
+pub struct DollyCollisionPlugin<B: CameraCollisionBackend>(PhantomData<fn() -> B>);
+
+impl<B: CameraCollisionBackend> Default for DollyCollisionPlugin<B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B: CameraCollisionBackend> Plugin for DollyCollisionPlugin<B> {
+    fn build(&self, app: &mut App) {
+        crate::system::add_dolly_plugin(app).add_systems(
+            PostUpdate,
+            update_collision_arms::<B>
+                .in_set(DollyPostProcessSet)
+                .before(crate::blend::update_rig_blends),
+        );
+    }
+}
+
+/// The arm of a rig, either in the rig itself or in a nested [`MovableLookAt`]
+fn collision_arm(rig: &mut Rig) -> Option<&mut CollisionArm> {
+    rig.drivers.iter_mut().find_map(|driver| {
+        let any = driver.as_mut().as_any_mut();
+        if any.is::<CollisionArm>() {
+            return any.downcast_mut::<CollisionArm>();
+        }
+        any.downcast_mut::<MovableLookAt>()?
+            .drivers
+            .iter_mut()
+            .find_map(|driver| driver.as_mut().as_any_mut().downcast_mut::<CollisionArm>())
+    })
+}
+
+pub(crate) fn update_collision_arms<B: CameraCollisionBackend>(
+    backend: StaticSystemParam<B::Param>,
+    time: Res<Time>,
+    mut rigs: Query<(Entity, &mut Rig)>,
+    rig_cameras: RigCameras,
+    mut cameras: Query<(&Camera, &mut Transform)>,
+) {
+    for (entity, mut rig) in &mut rigs {
+        let Some(arm) = collision_arm(rig.bypass_change_detection()) else {
+            continue;
+        };
+        let driven = rig_cameras.cameras(entity);
+        let mut ignore = driven.clone();
+        ignore.push(entity);
+        let correction = arm.collide::<B>(&backend, &ignore, time.delta_secs());
+        if correction == Vec3::ZERO {
+            continue;
+        }
+        // Rigs updated on change pick up the new arm length next frame
+        rig.set_changed();
+        rig.bypass_change_detection().final_transform.translation += correction;
+        for camera in driven {
+            if let Ok((camera, mut transform)) = cameras.get_mut(camera) {
+                if camera.is_active {
+                    transform.translation += correction;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use std::cell::OnceCell;
+
+use super::{resolve_driver, view::CameraViews};
+use crate::{prelude::*, rig_2d::smoothing_t};
+use bevy::{
+    math::bounding::{Aabb2d, Aabb3d},
+    prelude::*,
+};
+
+/// Area a [`Confiner`] keeps the camera's view inside.
+#[derive(Debug, Clone)]
+pub enum ConfinerBounds {
+    /// A rectangle in the XY plane, for 2D cameras and 3D cameras looking down -Z at Z = 0
+    Rect(Aabb2d),
+    /// A box the 3D camera stays inside, including its near plane
+    Box(Aabb3d),
+    /// A polygon in the XY plane. The view is pushed inside corner by corner, so this is
+    /// approximate where the polygon is narrower than the view
+    Polygon(Vec<Vec2>),
+}
+
+/// Clamps the camera so its view stays inside [`ConfinerBounds`].
+///
+/// Add it near the end of a [`Rig`] or [`Rig2d`]. The visible area is taken from the projection and
+/// viewport of the camera the rig drives, the active one if it drives several.
+/// When the bounds are smaller than the view, the view is centered on them.
+/// ```rust
+/// # use bevy::{math::bounding::Aabb2d, prelude::*};
+/// # use bevy_dolly::prelude::*;
+/// # let rig =
+/// Rig2d::builder()
+///     .with(Position2d::default())
+///     .with(Smooth2d::new_position(1.2))
+///     .with(Confiner::new(ConfinerBounds::Rect(Aabb2d::new(Vec2::ZERO, Vec2::new(1200., 400.)))))
+///     .build()
+/// # ;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Confiner {
+    pub bounds: ConfinerBounds,
+    /// Smoothness of pushing the camera back inside, 0 clamps it at the edge
+    pub damping: f32,
+    pub view: CameraView,
+    correction: Option<Vec3>,
+}
+
+impl Confiner {
+    pub fn new(bounds: ConfinerBounds) -> Self {
+        Self {
+            bounds,
+            damping: 0.,
+            view: CameraView::default(),
+            correction: None,
+        }
+    }
+
+    /// Lets the camera overshoot the edges a little and eases it back in
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Moves the center of a visible area so the area fits in the bounds
+    fn confine_xy(&self, center: Vec2, area: Rect) -> Vec2 {
+        match &self.bounds {
+            ConfinerBounds::Rect(rect) => confine_to_rect(center, area, rect.min, rect.max),
+            ConfinerBounds::Box(aabb) => {
+                confine_to_rect(center, area, aabb.min.truncate(), aabb.max.truncate())
+            }
+            ConfinerBounds::Polygon(points) => confine_to_polygon(center, area, points),
+        }
+    }
+
+    /// Eases towards the correction if damped
+    fn correct(&mut self, position: Vec3, confined: Vec3, delta_time_seconds: f32) -> Vec3 {
+        let target = confined - position;
+        let correction = match self.correction {
+            Some(previous) => previous.lerp(target, smoothing_t(self.damping, delta_time_seconds)),
+            None => target,
+        };
+        self.correction = Some(correction);
+        position + correction
+    }
+}
+
+fn confine_to_rect(center: Vec2, area: Rect, min: Vec2, max: Vec2) -> Vec2 {
+    let low = min - area.min;
+    let high = max - area.max;
+    Vec2::new(
+        confine_axis(center.x, low.x, high.x),
+        confine_axis(center.y, low.y, high.y),
+    )
+}
+
+fn confine_axis(value: f32, low: f32, high: f32) -> f32 {
+    if low > high {
+        (low + high) * 0.5
+    } else {
+        value.clamp(low, high)
+    }
+}
+
+fn contains(points: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    let mut j = points.len() - 1;
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[j]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+fn closest_on_edges(points: &[Vec2], point: Vec2) -> Vec2 {
+    let mut closest = points[0];
+    for (i, &a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        let ab = b - a;
+        let t = ((point - a).dot(ab) / ab.length_squared().max(f32::EPSILON)).clamp(0., 1.);
+        let candidate = a + ab * t;
+        if candidate.distance_squared(point) < closest.distance_squared(point) {
+            closest = candidate;
+        }
+    }
+    closest
+}
+
+/// Pushes the corners of the visible area inside the polygon one by one
+fn confine_to_polygon(center: Vec2, area: Rect, points: &[Vec2]) -> Vec2 {
+    if points.len() < 3 {
+        return center;
+    }
+    let mut center = if contains(points, center) {
+        center
+    } else {
+        closest_on_edges(points, center)
+    };
+    for _ in 0..4 {
+        let mut moved = false;
+        for corner in [
+            area.min,
+            Vec2::new(area.max.x, area.min.y),
+            area.max,
+            Vec2::new(area.min.x, area.max.y),
+        ] {
+            let corner = center + corner;
+            if !contains(points, corner) {
+                center += closest_on_edges(points, corner) - corner;
+                moved = true;
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+    center
+}
+
+impl RigDriver for Confiner {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        let mut transform = *params.parent;
+        let position = transform.translation;
+        let confined = match &self.bounds {
+            ConfinerBounds::Box(aabb) => {
+                // Keeps the near plane inside too
+                let padding = match self.view {
+                    CameraView::Perspective { fov, aspect, near } => {
+                        let half_height = (fov * 0.5).tan() * near;
+                        Vec2::new(half_height * aspect, half_height)
+                            .length()
+                            .hypot(near)
+                    }
+                    CameraView::Orthographic { .. } => 0.,
+                };
+                let (min, max) = (Vec3::from(aabb.min), Vec3::from(aabb.max));
+                Vec3::new(
+                    confine_axis(position.x, min.x + padding, max.x - padding),
+                    confine_axis(position.y, min.y + padding, max.y - padding),
+                    confine_axis(position.z, min.z + padding, max.z - padding),
+                )
+            }
+            _ => {
+                let area = self.view.visible_area(None, position.z);
+                self.confine_xy(position.truncate(), area)
+                    .extend(position.z)
+            }
+        };
+        transform.translation = self.correct(position, confined, params.delta_time_seconds);
+        transform
+    }
+}
+
+impl RigDriver2d for Confiner {
+    fn update(&mut self, parent: &Transform2d, delta_time_seconds: f32) -> Transform2d {
+        // Bounding box of the rolled visible area
+        let area = self
+            .view
+            .visible_area(Some(1. / parent.zoom.max(f32::EPSILON)), 0.);
+        let rotation = Rot2::radians(parent.roll);
+        let corners = [
+            area.min,
+            Vec2::new(area.max.x, area.min.y),
+            area.max,
+            Vec2::new(area.min.x, area.max.y),
+        ]
+        .map(|corner| rotation * corner);
+        let area = corners
+            .iter()
+            .fold(Rect::from_corners(corners[0], corners[0]), |rect, &c| {
+                rect.union_point(c)
+            });
+
+        let position = parent.position.extend(0.);
+        let confined = self.confine_xy(parent.position, area).extend(0.);
+        Transform2d {
+            position: self
+                .correct(position, confined, delta_time_seconds)
+                .truncate(),
+            ..*parent
+        }
+    }
+}
+
+/// Copies the view of the camera each confined rig drives into its [`Confiner`]
+#[allow(clippy::type_complexity)]
+pub(crate) fn resolve_confiner_views(
+    mut rigs: Query<(Entity, Option<&mut Rig>, Option<&mut Rig2d>), Or<(With<Rig>, With<Rig2d>)>>,
+    cameras: CameraViews,
+) {
+    for (entity, rig, rig_2d) in &mut rigs {
+        // Only looked up for confined rigs
+        let view = OnceCell::new();
+        let update = |confiner: &mut Confiner| {
+            let Some(view) = *view.get_or_init(|| cameras.of(entity)) else {
+                return false;
+            };
+            let changed = confiner.view != view;
+            confiner.view = view;
+            changed
+        };
+        if let Some(mut rig) = rig {
+            resolve_driver(&mut rig, update);
+        }
+        if let Some(mut rig) = rig_2d {
+            resolve_driver(&mut rig, update);
+        }
+    }
+}
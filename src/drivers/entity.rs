@@ -0,0 +1,265 @@
+use super::resolve_driver;
+use crate::{
+    prelude::*,
+    system::{entity_transform, EntityTransforms},
+};
+use bevy::prelude::*;
+
+/// Places the rig at an entity, like [`Position`] and optionally [`Rotation`] fed from its transform.
+///
+/// The entity's transform is read by [`DollyPlugin`] every frame before the rig is evaluated.
+/// When the entity despawns, the rig stays at its last known position and [`FollowEntity::is_lost`]
+/// turns true, until [`FollowEntity::entity`] is pointed at another entity.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn rig(player: Entity) -> Rig {
+/// Rig::builder()
+///     .with(FollowEntity::new(player).with_offset(Vec3::Y))
+///     .with(Arm::new(Vec3::Z * 4.0))
+///     .build()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FollowEntity {
+    pub entity: Entity,
+    /// Added to the entity's position, in world space
+    pub offset: Vec3,
+    /// Also take on the entity's rotation
+    pub follow_rotation: bool,
+    /// Read the entity's [`GlobalTransform`] even if it has no parent, children always use it.
+    /// The global transform isn't propagated yet when the rig is evaluated, so it lags a frame behind.
+    pub use_global_transform: bool,
+    /// A disabled driver keeps the last position it read
+    pub enabled: bool,
+    target: Option<Transform>,
+    lost: bool,
+}
+
+impl FollowEntity {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            offset: Vec3::ZERO,
+            follow_rotation: false,
+            use_global_transform: false,
+            enabled: true,
+            target: None,
+            lost: false,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_rotation(mut self, follow_rotation: bool) -> Self {
+        self.follow_rotation = follow_rotation;
+        self
+    }
+
+    pub fn with_global_transform(mut self, use_global_transform: bool) -> Self {
+        self.use_global_transform = use_global_transform;
+        self
+    }
+
+    /// Last known transform of the entity
+    pub fn target(&self) -> Option<Transform> {
+        self.target
+    }
+
+    /// Whether the entity no longer exists
+    pub fn is_lost(&self) -> bool {
+        self.lost
+    }
+
+    /// Reads the entity's transform, returns whether the output of the driver changed
+    fn resolve(&mut self, transforms: &EntityTransforms) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        match entity_transform(transforms, self.entity, self.use_global_transform) {
+            Some(target) => {
+                let changed = self.target != Some(target);
+                self.target = Some(target);
+                self.lost = false;
+                changed
+            }
+            None => {
+                self.lost = true;
+                false
+            }
+        }
+    }
+}
+
+impl RigDriver for FollowEntity {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        let Some(target) = self.target else {
+            return *params.parent;
+        };
+        Transform {
+            translation: target.translation + self.offset,
+            rotation: if self.follow_rotation {
+                target.rotation
+            } else {
+                params.parent.rotation
+            },
+            scale: Vec3::ONE,
+        }
+    }
+}
+
+impl RigDriver2d for FollowEntity {
+    fn update(&mut self, parent: &Transform2d, _: f32) -> Transform2d {
+        let Some(target) = self.target else {
+            return *parent;
+        };
+        Transform2d {
+            position: (target.translation + self.offset).truncate(),
+            roll: if self.follow_rotation {
+                target.rotation.to_euler(EulerRot::ZYX).0
+            } else {
+                parent.roll
+            },
+            ..*parent
+        }
+    }
+}
+
+/// Rotates the rig to look at an entity, a [`LookAt`] fed from its transform.
+///
+/// Resolved like [`FollowEntity`], and keeps looking at the last known position
+/// when the entity despawns.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn rig(player: Entity) -> Rig {
+/// Rig::builder()
+///     .with(Position::new(Vec3::Y * 3.0))
+///     .with(LookAtEntity::new(player).tracking_smoothness(1.25))
+///     .build()
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LookAtEntity {
+    pub entity: Entity,
+    /// Added to the entity's position, in world space
+    pub offset: Vec3,
+    /// Exponential smoothing factor, see [`LookAt::tracking_smoothness`]
+    pub smoothness: f32,
+    /// See [`FollowEntity::use_global_transform`]
+    pub use_global_transform: bool,
+    /// A disabled driver keeps looking at the last position it read
+    pub enabled: bool,
+    predictive: bool,
+    look_at: Option<LookAt>,
+    lost: bool,
+}
+
+impl LookAtEntity {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            offset: Vec3::ZERO,
+            smoothness: 0.,
+            use_global_transform: false,
+            enabled: true,
+            predictive: false,
+            look_at: None,
+            lost: false,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn tracking_smoothness(mut self, smoothness: f32) -> Self {
+        self.smoothness = smoothness;
+        self
+    }
+
+    /// See [`LookAt::tracking_predictive`]
+    pub fn tracking_predictive(mut self, predictive: bool) -> Self {
+        self.predictive = predictive;
+        self
+    }
+
+    pub fn with_global_transform(mut self, use_global_transform: bool) -> Self {
+        self.use_global_transform = use_global_transform;
+        self
+    }
+
+    /// Last known position looked at, including the offset
+    pub fn target(&self) -> Option<Vec3> {
+        self.look_at.as_ref().map(|look_at| look_at.target)
+    }
+
+    /// Whether the entity no longer exists
+    pub fn is_lost(&self) -> bool {
+        self.lost
+    }
+
+    /// Reads the entity's transform, returns whether the output of the driver changed
+    fn resolve(&mut self, transforms: &EntityTransforms) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let Some(transform) = entity_transform(transforms, self.entity, self.use_global_transform)
+        else {
+            self.lost = true;
+            return false;
+        };
+        self.lost = false;
+
+        let target = transform.translation + self.offset;
+        match &mut self.look_at {
+            Some(look_at) => {
+                // Smoothing keeps the rig moving after the target stopped
+                let changed = look_at.target != target || look_at.smoothness > 0.;
+                look_at.target = target;
+                look_at.smoothness = self.smoothness;
+                changed
+            }
+            // Created on the first read, so smoothing starts at the entity
+            None => {
+                self.look_at = Some(
+                    LookAt::new(target)
+                        .tracking_smoothness(self.smoothness)
+                        .tracking_predictive(self.predictive),
+                );
+                true
+            }
+        }
+    }
+}
+
+impl RigDriver for LookAtEntity {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        match &mut self.look_at {
+            Some(look_at) => look_at.update(params),
+            None => *params.parent,
+        }
+    }
+}
+
+/// Reads the transforms of the entities followed by [`FollowEntity`] and [`LookAtEntity`] drivers
+pub(crate) fn resolve_entity_drivers(
+    mut rigs: Query<&mut Rig>,
+    mut rigs_2d: Query<&mut Rig2d>,
+    transforms: EntityTransforms,
+) {
+    let follow = |follow: &mut FollowEntity| follow.resolve(&transforms);
+    for mut rig in &mut rigs {
+        resolve_driver(&mut rig, follow);
+        resolve_driver(&mut rig, |look_at: &mut LookAtEntity| {
+            look_at.resolve(&transforms)
+        });
+    }
+    for mut rig in &mut rigs_2d {
+        resolve_driver(&mut rig, follow);
+    }
+}
@@ -30,6 +30,12 @@ impl MovableLookAt {
 #[derive(Component, Debug, Deref, DerefMut)]
 pub struct MovableLookAt(CameraRig);
 
+impl From<CameraRig> for MovableLookAt {
+    fn from(rig: CameraRig) -> Self {
+        Self(rig)
+    }
+}
+
 // Turn the nested rig into a driver, so it can be used in another rig.
 impl RigDriver for MovableLookAt {
     fn update(&mut self, params: RigUpdateParams) -> Transform {
@@ -49,13 +49,18 @@ impl Fpv {
         boost_mult: f32,
         lock_y: bool,
     ) -> Vec3 {
-        if lock_y {
-            let (mut euler, a) = self.final_transform.rotation.to_axis_angle();
-            euler.x = 0.;
-            euler.z = 0.;
-            self.final_transform.rotation = Quat::from_axis_angle(euler, a);
-        }
-        self.final_transform.rotation
+        // Locked to the horizontal plane only the yaw turns the movement, taken from the
+        // rotation for rigs without a YawPitch
+        let rotation = if lock_y {
+            let yaw = match self.try_driver::<YawPitch>() {
+                Some(yaw_pitch) => yaw_pitch.yaw_degrees.to_radians(),
+                None => self.final_transform.rotation.to_euler(EulerRot::YXZ).0,
+            };
+            Quat::from_rotation_y(yaw)
+        } else {
+            self.final_transform.rotation
+        };
+        rotation
             * Vec3::new(player_position.x, player_position.y, player_position.z)
                 .clamp_length_max(1.0)
             * boost_mult.powf(boost)
@@ -66,6 +71,12 @@ impl Fpv {
 #[derive(Component, Debug, Deref, DerefMut)]
 pub struct Fpv(CameraRig);
 
+impl From<CameraRig> for Fpv {
+    fn from(rig: CameraRig) -> Self {
+        Self(rig)
+    }
+}
+
 // Turn the nested rig into a driver, so it can be used in another rig.
 impl RigDriver for Fpv {
     fn update(&mut self, params: dolly::rig::RigUpdateParams) -> Transform {
@@ -0,0 +1,201 @@
+use std::cell::OnceCell;
+
+use super::{resolve_driver, view::CameraViews};
+use crate::{
+    prelude::*,
+    rig_2d::smoothing_t,
+    system::{entity_transform, EntityTransforms},
+};
+use bevy::prelude::*;
+
+/// Keeps an entity inside zones of the screen by moving the camera in its view plane.
+///
+/// While the entity is inside the dead zone the camera doesn't move. In the soft zone around it
+/// the camera catches up with [`Framing::damping`], and the entity never leaves the soft zone.
+/// Zones are half sizes in fractions of half the screen, so `Vec2::ONE` is the whole screen.
+/// They are measured with the projection and viewport of the camera the rig drives, like
+/// [`Confiner`]. The camera keeps the offset it was moved by on top of the previous drivers,
+/// so add it after them, ie. after a fixed [`Position2d`] or an `Arm`.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn rig(player: Entity) -> Rig2d {
+/// Rig2d::builder()
+///     .with(Position2d::default())
+///     .with(
+///         Framing::new(player)
+///             .with_dead_zone(Vec2::new(0.2, 0.1))
+///             .with_soft_zone(Vec2::new(0.6, 0.5))
+///             .with_damping(0.5),
+///     )
+///     .build()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Framing {
+    pub target: Entity,
+    /// Added to the entity's position, in world space
+    pub target_offset: Vec3,
+    /// Where on screen the entity is framed, from -1 to 1 on both axes
+    pub screen_position: Vec2,
+    pub dead_zone: Vec2,
+    /// The hard limit, the entity is never framed outside of it
+    pub soft_zone: Vec2,
+    /// Smoothness of catching up in the soft zone, 0 keeps the entity on the edge of the dead zone
+    pub damping: f32,
+    pub view: CameraView,
+    target_position: Option<Vec3>,
+    offset: Vec3,
+    settling: bool,
+}
+
+impl Framing {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            target_offset: Vec3::ZERO,
+            screen_position: Vec2::ZERO,
+            dead_zone: Vec2::splat(0.1),
+            soft_zone: Vec2::splat(0.5),
+            damping: 1.,
+            view: CameraView::default(),
+            target_position: None,
+            offset: Vec3::ZERO,
+            settling: false,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.target_offset = offset;
+        self
+    }
+
+    pub fn with_screen_position(mut self, screen_position: Vec2) -> Self {
+        self.screen_position = screen_position;
+        self
+    }
+
+    pub fn with_dead_zone(mut self, dead_zone: Vec2) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    pub fn with_soft_zone(mut self, soft_zone: Vec2) -> Self {
+        self.soft_zone = soft_zone;
+        self
+    }
+
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// How far the camera has been moved from the output of the previous drivers
+    pub fn offset(&self) -> Vec3 {
+        self.offset
+    }
+
+    /// Whether the camera is still catching up with the entity
+    pub fn is_settling(&self) -> bool {
+        self.settling
+    }
+
+    /// Copies the entity's position and the camera's view, returns whether the rig needs an update.
+    /// The last known position is kept when the entity despawns.
+    fn resolve(&mut self, target: Option<Vec3>, view: Option<CameraView>) -> bool {
+        let target = target
+            .map(|target| target + self.target_offset)
+            .or(self.target_position);
+        let view = view.unwrap_or(self.view);
+        let changed = target != self.target_position || view != self.view || self.settling;
+        self.target_position = target;
+        self.view = view;
+        changed
+    }
+
+    /// Motion of the camera in its view plane that frames the entity, from where the entity is
+    /// relative to the area visible at its depth
+    fn frame(&mut self, local: Vec2, area: Rect, delta_time_seconds: f32) -> Vec2 {
+        let half_size = area.half_size().max(Vec2::splat(f32::EPSILON));
+        let framed = (local - area.center()) / half_size - self.screen_position;
+        let dead_zone = self.dead_zone.max(Vec2::ZERO);
+        let soft_range = self.soft_zone.max(dead_zone) - dead_zone;
+        let excess = framed - framed.clamp(-dead_zone, dead_zone);
+
+        let t = smoothing_t(self.damping, delta_time_seconds);
+        let remaining = (excess * (1. - t)).clamp(-soft_range, soft_range);
+        self.settling = remaining.length_squared() > 1e-8;
+        (excess - remaining) * half_size
+    }
+}
+
+impl RigDriver for Framing {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        let mut transform = *params.parent;
+        transform.translation += self.offset;
+        let Some(target) = self.target_position else {
+            return transform;
+        };
+
+        let local = transform.rotation.inverse() * (target - transform.translation);
+        let depth = -local.z;
+        if depth <= 0. && matches!(self.view, CameraView::Perspective { .. }) {
+            // Behind the camera, there is no place on screen to keep it at
+            self.settling = false;
+            return transform;
+        }
+        let area = self.view.visible_area(None, depth);
+        let motion = transform.rotation
+            * self
+                .frame(local.truncate(), area, params.delta_time_seconds)
+                .extend(0.);
+        self.offset += motion;
+        transform.translation += motion;
+        transform
+    }
+}
+
+impl RigDriver2d for Framing {
+    fn update(&mut self, parent: &Transform2d, delta_time_seconds: f32) -> Transform2d {
+        let mut transform = *parent;
+        transform.position += self.offset.truncate();
+        let Some(target) = self.target_position else {
+            return transform;
+        };
+
+        let rotation = Rot2::radians(parent.roll);
+        let local = rotation.inverse() * (target.truncate() - transform.position);
+        let area = self
+            .view
+            .visible_area(Some(1. / parent.zoom.max(f32::EPSILON)), 0.);
+        let motion = rotation * self.frame(local, area, delta_time_seconds);
+        self.offset += motion.extend(0.);
+        transform.position += motion;
+        transform
+    }
+}
+
+/// Copies the framed entity's position and the view of the camera into every [`Framing`]
+#[allow(clippy::type_complexity)]
+pub(crate) fn resolve_framing(
+    mut rigs: Query<(Entity, Option<&mut Rig>, Option<&mut Rig2d>), Or<(With<Rig>, With<Rig2d>)>>,
+    cameras: CameraViews,
+    transforms: EntityTransforms,
+) {
+    let position = |entity| entity_transform(&transforms, entity, false).map(|t| t.translation);
+
+    for (entity, rig, rig_2d) in &mut rigs {
+        // Only looked up for rigs with a framing driver
+        let view = OnceCell::new();
+        let resolve = |framing: &mut Framing| {
+            let view = *view.get_or_init(|| cameras.of(entity));
+            framing.resolve(position(framing.target), view)
+        };
+        if let Some(mut rig) = rig {
+            resolve_driver(&mut rig, resolve);
+        }
+        if let Some(mut rig) = rig_2d {
+            resolve_driver(&mut rig, resolve);
+        }
+    }
+}
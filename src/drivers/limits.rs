@@ -0,0 +1,171 @@
+use crate::{ordered, prelude::*};
+use bevy::prelude::*;
+
+/// Limits the pitch, and optionally the yaw, of the output of the previous drivers.
+///
+/// Yaw limits are relative to a reference direction, ie. the front of a turret or the seat
+/// of a vehicle, and can be moved along with it by [`YawPitchLimits::set_reference`].
+/// With a softness the rotation slows down as it nears a limit instead of stopping dead.
+/// [`DollyPlugin`] also clamps the `YawPitch` of the rig, nested in an [`Fpv`] or not,
+/// so input past a limit doesn't have to be undone before the camera turns back.
+/// The output has no roll.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # let rig =
+/// Rig::builder()
+///     .with(Position::new(Vec3::Y))
+///     .with(YawPitch::new())
+///     .with(
+///         YawPitchLimits::new()
+///             .with_pitch(-30., 45.)
+///             .with_yaw(Vec3::NEG_Z, -60., 60.)
+///             .with_softness(10.),
+///     )
+///     .with(Smooth::new_rotation(0.2))
+///     .build()
+/// # ;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YawPitchLimits {
+    /// Pitch range, in either order
+    pub min_pitch_degrees: f32,
+    pub max_pitch_degrees: f32,
+    /// Yaw range relative to [`YawPitchLimits::reference_yaw_degrees`], within -180 to 180,
+    /// in either order
+    pub yaw_degrees: Option<(f32, f32)>,
+    /// Yaw of the direction the yaw range is centered on
+    pub reference_yaw_degrees: f32,
+    /// Angle before a limit over which the rotation eases into it, 0 for hard limits
+    pub softness_degrees: f32,
+}
+
+impl Default for YawPitchLimits {
+    fn default() -> Self {
+        Self {
+            min_pitch_degrees: -89.,
+            max_pitch_degrees: 89.,
+            yaw_degrees: None,
+            reference_yaw_degrees: 0.,
+            softness_degrees: 0.,
+        }
+    }
+}
+
+impl YawPitchLimits {
+    /// Keeps the pitch just short of the poles, so the camera can't flip over
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_pitch(mut self, min_degrees: f32, max_degrees: f32) -> Self {
+        self.min_pitch_degrees = min_degrees;
+        self.max_pitch_degrees = max_degrees;
+        self
+    }
+
+    /// Limits the yaw to a range around a direction
+    pub fn with_yaw(mut self, reference: Vec3, min_degrees: f32, max_degrees: f32) -> Self {
+        self.set_reference(reference);
+        self.yaw_degrees = Some((min_degrees, max_degrees));
+        self
+    }
+
+    pub fn with_softness(mut self, softness_degrees: f32) -> Self {
+        self.softness_degrees = softness_degrees;
+        self
+    }
+
+    /// Centers the yaw range on a direction, its vertical part is ignored
+    pub fn set_reference(&mut self, reference: Vec3) {
+        if reference.x != 0. || reference.z != 0. {
+            self.reference_yaw_degrees = (-reference.x).atan2(-reference.z).to_degrees();
+        }
+    }
+
+    /// Applies the limits to a yaw and pitch, in degrees
+    pub fn limit(&self, yaw_degrees: f32, pitch_degrees: f32) -> (f32, f32) {
+        self.apply(yaw_degrees, pitch_degrees, self.softness_degrees, 0.)
+    }
+
+    /// Clamps a yaw and pitch to the limits widened by the softness,
+    /// as far as the soft limits can still tell values apart
+    fn clamp_input(&self, yaw_degrees: f32, pitch_degrees: f32) -> (f32, f32) {
+        self.apply(yaw_degrees, pitch_degrees, 0., self.softness_degrees)
+    }
+
+    fn apply(&self, yaw: f32, pitch: f32, softness: f32, margin: f32) -> (f32, f32) {
+        let (min, max) = ordered(self.min_pitch_degrees, self.max_pitch_degrees);
+        let pitch = soft_clamp(pitch, min - margin, max + margin, softness);
+        let yaw = match self.yaw_degrees {
+            Some((min, max)) => {
+                let (min, max) = ordered(min, max);
+                // Relative to the reference, wrapped so the closest limit is picked
+                let relative = (yaw - self.reference_yaw_degrees + 180.).rem_euclid(360.) - 180.;
+                yaw + soft_clamp(relative, min - margin, max + margin, softness) - relative
+            }
+            None => yaw,
+        };
+        (yaw, pitch)
+    }
+}
+
+/// Clamps to a range, easing into the bounds exponentially over `softness`
+fn soft_clamp(value: f32, min: f32, max: f32, softness: f32) -> f32 {
+    let softness = softness.min((max - min) * 0.5);
+    if softness <= 0. {
+        return value.clamp(min, max);
+    }
+    let ease = |over: f32| softness * (1. - (-over / softness).exp());
+    if value > max - softness {
+        max - softness + ease(value - (max - softness))
+    } else if value < min + softness {
+        min + softness - ease(min + softness - value)
+    } else {
+        value
+    }
+}
+
+impl RigDriver for YawPitchLimits {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        let (yaw, pitch, _) = params.parent.rotation.to_euler(EulerRot::YXZ);
+        let (yaw, pitch) = self.limit(yaw.to_degrees(), pitch.to_degrees());
+        Transform {
+            translation: params.parent.translation,
+            rotation: Quat::from_euler(EulerRot::YXZ, yaw.to_radians(), pitch.to_radians(), 0.),
+            scale: Vec3::ONE,
+        }
+    }
+}
+
+/// Clamps the `YawPitch` of rigs with [`YawPitchLimits`], directly in the rig or in its [`Fpv`]
+pub(crate) fn apply_yaw_pitch_limits(mut rigs: Query<&mut Rig>) {
+    for mut rig in &mut rigs {
+        let Some(limits) = rig.try_driver::<YawPitchLimits>().copied() else {
+            continue;
+        };
+        let nested = rig.try_driver::<YawPitch>().is_none();
+        let yaw_pitch = if nested {
+            rig.try_driver::<Fpv>()
+                .and_then(|fpv| fpv.try_driver::<YawPitch>())
+        } else {
+            rig.try_driver::<YawPitch>()
+        };
+        let Some(yaw_pitch) = yaw_pitch else {
+            continue;
+        };
+
+        let current = (yaw_pitch.yaw_degrees, yaw_pitch.pitch_degrees);
+        let (yaw, pitch) = limits.clamp_input(current.0, current.1);
+        if (yaw, pitch) == current {
+            continue;
+        }
+        let yaw_pitch = if nested {
+            rig.driver_mut::<Fpv>().driver_mut::<YawPitch>()
+        } else {
+            rig.driver_mut::<YawPitch>()
+        };
+        yaw_pitch.yaw_degrees = yaw;
+        yaw_pitch.pitch_degrees = pitch;
+    }
+}
@@ -0,0 +1,199 @@
+use super::resolve_driver;
+use crate::{
+    prelude::*,
+    rig_2d::smoothing_t,
+    system::{entity_transform, EntityTransforms},
+};
+use bevy::prelude::*;
+
+/// Velocity of an entity followed by a [`LookAhead`], ie. copied from a physics crate.
+/// Without it the velocity is derived from the entity's successive transforms.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct TargetVelocity(pub Vec3);
+
+/// Offsets the rig in the direction an entity is moving, so more of what lies ahead is in view.
+///
+/// The velocity comes from the entity's [`TargetVelocity`], or is derived from its transform
+/// every frame. Entities moved in `FixedUpdate` need a [`FixedStepTarget`] for a steady velocity.
+/// Add it after the driver that follows the entity and before an `Arm`, to move the pivot and with it
+/// the point looked at, or last to move only the camera. Moving out and returning to center are
+/// smoothed separately, so the view can lead quickly and settle back slowly when the entity stops.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn rig(player: Entity) -> Rig2d {
+/// Rig2d::builder()
+///     .with(FollowEntity::new(player))
+///     .with(
+///         LookAhead::new(player)
+///             .with_axes(Vec3::X)
+///             .with_time(0.5)
+///             .with_max_distance(200.),
+///     )
+///     .build()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LookAhead {
+    pub entity: Entity,
+    /// Seconds of the entity's velocity to look ahead by
+    pub time: f32,
+    pub max_distance: f32,
+    /// Multiplies the velocity in world space, ie. `Vec3::X` to only look ahead sideways
+    pub axes: Vec3,
+    /// Smoothness of moving out, when the entity speeds up or turns
+    pub lead_smoothness: f32,
+    /// Smoothness of returning to center, when the entity slows down or stops
+    pub return_smoothness: f32,
+    /// See [`FollowEntity::use_global_transform`]
+    pub use_global_transform: bool,
+    /// A disabled driver keeps looking ahead by the last velocity it read
+    pub enabled: bool,
+    velocity: Vec3,
+    last_position: Option<Vec3>,
+    offset: Vec3,
+    settling: bool,
+}
+
+impl LookAhead {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            time: 0.5,
+            max_distance: 5.,
+            axes: Vec3::ONE,
+            lead_smoothness: 0.5,
+            return_smoothness: 1.,
+            use_global_transform: false,
+            enabled: true,
+            velocity: Vec3::ZERO,
+            last_position: None,
+            offset: Vec3::ZERO,
+            settling: false,
+        }
+    }
+
+    pub fn with_time(mut self, time: f32) -> Self {
+        self.time = time;
+        self
+    }
+
+    pub fn with_max_distance(mut self, max_distance: f32) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    pub fn with_axes(mut self, axes: Vec3) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    pub fn with_smoothness(mut self, lead_smoothness: f32, return_smoothness: f32) -> Self {
+        self.lead_smoothness = lead_smoothness;
+        self.return_smoothness = return_smoothness;
+        self
+    }
+
+    pub fn with_global_transform(mut self, use_global_transform: bool) -> Self {
+        self.use_global_transform = use_global_transform;
+        self
+    }
+
+    /// Last velocity read from the entity
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// Current offset of the rig, in world space
+    pub fn offset(&self) -> Vec3 {
+        self.offset
+    }
+
+    /// Reads the entity's velocity, returns whether the rig needs an update.
+    /// The velocity drops to zero when the entity despawns, so the rig returns to center.
+    fn resolve(
+        &mut self,
+        transforms: &EntityTransforms,
+        velocities: &Query<&TargetVelocity>,
+        delta_time_seconds: f32,
+    ) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let velocity = if let Ok(velocity) = velocities.get(self.entity) {
+            velocity.0
+        } else if let Some(transform) =
+            entity_transform(transforms, self.entity, self.use_global_transform)
+        {
+            let position = transform.translation;
+            let velocity = match self.last_position {
+                Some(last) if delta_time_seconds > 0. => (position - last) / delta_time_seconds,
+                // Paused, or the first read
+                _ => self.velocity,
+            };
+            self.last_position = Some(position);
+            velocity
+        } else {
+            self.last_position = None;
+            Vec3::ZERO
+        };
+
+        let changed = velocity != self.velocity || self.settling;
+        self.velocity = velocity;
+        changed
+    }
+
+    /// Moves the offset towards the velocity, returns the new offset
+    fn advance(&mut self, delta_time_seconds: f32) -> Vec3 {
+        let desired = (self.velocity * self.axes * self.time).clamp_length_max(self.max_distance);
+        let smoothness = if desired.length_squared() >= self.offset.length_squared() {
+            self.lead_smoothness
+        } else {
+            self.return_smoothness
+        };
+        self.offset = self
+            .offset
+            .lerp(desired, smoothing_t(smoothness, delta_time_seconds));
+        self.settling = self.offset.distance_squared(desired) > 1e-8;
+        self.offset
+    }
+}
+
+impl RigDriver for LookAhead {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        Transform {
+            translation: params.parent.translation + self.advance(params.delta_time_seconds),
+            ..*params.parent
+        }
+    }
+}
+
+impl RigDriver2d for LookAhead {
+    fn update(&mut self, parent: &Transform2d, delta_time_seconds: f32) -> Transform2d {
+        Transform2d {
+            position: parent.position + self.advance(delta_time_seconds).truncate(),
+            ..*parent
+        }
+    }
+}
+
+/// Reads the velocity of the entities followed by [`LookAhead`] drivers
+pub(crate) fn resolve_look_ahead(
+    time: Res<Time>,
+    mut rigs: Query<&mut Rig>,
+    mut rigs_2d: Query<&mut Rig2d>,
+    transforms: EntityTransforms,
+    velocities: Query<&TargetVelocity>,
+) {
+    let delta_time_seconds = time.delta_secs();
+
+    let resolve = |look_ahead: &mut LookAhead| {
+        look_ahead.resolve(&transforms, &velocities, delta_time_seconds)
+    };
+    for mut rig in &mut rigs {
+        resolve_driver(&mut rig, resolve);
+    }
+    for mut rig in &mut rigs_2d {
+        resolve_driver(&mut rig, resolve);
+    }
+}
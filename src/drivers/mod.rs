@@ -1,2 +1,58 @@
+pub mod collision;
+pub mod confiner;
+pub mod entity;
 pub mod follow;
 pub mod fpv;
+pub mod framing;
+pub mod limits;
+pub mod look_ahead;
+pub mod occlusion;
+pub mod platform_snap;
+pub mod shake;
+pub mod target_group;
+pub mod track;
+pub mod view;
+
+use std::any::Any;
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// A [`Rig`] or [`Rig2d`], for resolvers of drivers that work in both
+pub(crate) trait DriverRig: Component {
+    fn drivers_mut(&mut self) -> impl Iterator<Item = &mut dyn Any>;
+}
+
+impl DriverRig for Rig {
+    fn drivers_mut(&mut self) -> impl Iterator<Item = &mut dyn Any> {
+        self.drivers
+            .iter_mut()
+            .map(|driver| driver.as_mut().as_any_mut())
+    }
+}
+
+impl DriverRig for Rig2d {
+    fn drivers_mut(&mut self) -> impl Iterator<Item = &mut dyn Any> {
+        self.drivers.iter_mut().map(|driver| driver.as_any_mut())
+    }
+}
+
+/// Runs `resolve` on every driver of type `T` in a rig, ie. to copy the transform of an entity
+/// into it, and flags the rig as changed only if `resolve` returns true for one of them.
+///
+/// Resolvers run every frame. Flagging every rig they visit would make the [`Dolly`] systems
+/// that only update changed rigs, ie. [`Dolly::update_active`], update them every frame too.
+pub(crate) fn resolve_driver<T: Any, R: DriverRig>(
+    rig: &mut Mut<R>,
+    mut resolve: impl FnMut(&mut T) -> bool,
+) {
+    let mut changed = false;
+    for driver in rig.bypass_change_detection().drivers_mut() {
+        if let Some(driver) = driver.downcast_mut::<T>() {
+            changed |= resolve(driver);
+        }
+    }
+    if changed {
+        rig.set_changed();
+    }
+}
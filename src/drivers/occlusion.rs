@@ -0,0 +1,319 @@
+use std::marker::PhantomData;
+
+use super::{collision::update_collision_arms, resolve_driver};
+use crate::{prelude::*, rig_2d::smoothing_t, system::RigCameras};
+use bevy::{
+    ecs::{
+        entity::EntityHashSet,
+        system::{StaticSystemParam, SystemParamItem},
+    },
+    prelude::*,
+};
+
+/// Watches the line of sight from the camera to the target the rig looks at.
+///
+/// Add it next to a [`Rig`] with a [`LookAt`] or [`LookAtEntity`] driver. Every frame the
+/// [`CameraCollisionBackend`] of a [`DollyOcclusionPlugin`] casts from the target to the camera,
+/// [`TargetOccluded`] and [`TargetVisible`] are sent when the line of sight changes.
+/// Add an [`OcclusionOrbit`] driver to have the rig look around what's in the way.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// # fn setup(mut commands: Commands, player: Entity) {
+/// commands.spawn((
+///     MainCamera,
+///     Camera3d::default(),
+///     Rig::builder()
+///         .with(Position::new(Vec3::new(0., 3., 8.)))
+///         .with(LookAtEntity::new(player))
+///         .build(),
+///     Occlusion::new().with_marked_occluders(true),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct Occlusion {
+    /// Mark the entities in the way with [`Occluder`], ie. to fade them out
+    pub mark_occluders: bool,
+    /// Radius of the line of sight, a thin ray by default
+    pub radius: f32,
+    /// Entities that never block the view, the followed entity is always ignored
+    pub ignore: Vec<Entity>,
+    occluders: Vec<Entity>,
+}
+
+impl Occlusion {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_marked_occluders(mut self, mark_occluders: bool) -> Self {
+        self.mark_occluders = mark_occluders;
+        self
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn with_ignored(mut self, entity: Entity) -> Self {
+        self.ignore.push(entity);
+        self
+    }
+
+    pub fn is_occluded(&self) -> bool {
+        !self.occluders.is_empty()
+    }
+
+    /// Entities between the camera and the target, closest to the target first
+    pub fn occluders(&self) -> &[Entity] {
+        &self.occluders
+    }
+}
+
+/// Marks an entity that hides the target of a rig with [`Occlusion::mark_occluders`],
+/// removed once it no longer does.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Occluder;
+
+/// Sent when the target of a rig with [`Occlusion`] gets hidden
+#[derive(Event, Debug, Clone)]
+pub struct TargetOccluded {
+    pub rig: Entity,
+    pub occluders: Vec<Entity>,
+}
+
+/// Sent when the target of a rig with [`Occlusion`] is visible again
+#[derive(Event, Debug, Clone)]
+pub struct TargetVisible {
+    pub rig: Entity,
+}
+
+/// Swings the camera around the look-at target, until nothing blocks the view.
+///
+/// Add it at the end of a rig with [`Occlusion`]. While the target is hidden the driver tries
+/// angles further and further around the target's Y axis, and swings back once the view
+/// from the original angle is clear again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcclusionOrbit {
+    /// Furthest the camera swings either way, in degrees
+    pub max_angle_degrees: f32,
+    /// Angle between the tried positions, in degrees
+    pub step_degrees: f32,
+    /// Exponential smoothing factor of the swing
+    pub smoothness: f32,
+    angle: f32,
+    target_angle: f32,
+    target: Option<Vec3>,
+    pivot: Vec3,
+}
+
+impl Default for OcclusionOrbit {
+    fn default() -> Self {
+        Self {
+            max_angle_degrees: 90.,
+            step_degrees: 15.,
+            smoothness: 1.,
+            angle: 0.,
+            target_angle: 0.,
+            target: None,
+            pivot: Vec3::ZERO,
+        }
+    }
+}
+
+impl OcclusionOrbit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_angle_degrees(mut self, max_angle_degrees: f32) -> Self {
+        self.max_angle_degrees = max_angle_degrees;
+        self
+    }
+
+    pub fn with_step_degrees(mut self, step_degrees: f32) -> Self {
+        self.step_degrees = step_degrees;
+        self
+    }
+
+    pub fn with_smoothness(mut self, smoothness: f32) -> Self {
+        self.smoothness = smoothness;
+        self
+    }
+
+    /// Current swing around the target, in degrees
+    pub fn angle_degrees(&self) -> f32 {
+        self.angle.to_degrees()
+    }
+
+    /// Camera position at a swing angle, in radians
+    fn position_at(&self, target: Vec3, angle: f32) -> Vec3 {
+        target + Quat::from_rotation_y(angle) * (self.pivot - target)
+    }
+
+    /// Closest angle, starting from the current one, where `is_clear` holds
+    fn find_clear(&self, is_clear: impl Fn(f32) -> bool) -> Option<f32> {
+        let step = self.step_degrees.max(1.).to_radians();
+        let max = self.max_angle_degrees.to_radians();
+        let steps = (max / step).floor() as i32;
+        (1..=steps)
+            .flat_map(|i| [i, -i])
+            .map(|i| self.target_angle + i as f32 * step)
+            .filter(|angle| angle.abs() <= max)
+            .find(|&angle| is_clear(angle))
+    }
+}
+
+impl RigDriver for OcclusionOrbit {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        self.pivot = params.parent.translation;
+        let Some(target) = self.target else {
+            return *params.parent;
+        };
+
+        self.angle = self.angle.lerp(
+            self.target_angle,
+            smoothing_t(self.smoothness, params.delta_time_seconds),
+        );
+        if (self.angle - self.target_angle).abs() < 1e-4 {
+            self.angle = self.target_angle;
+        }
+
+        Transform {
+            translation: self.position_at(target, self.angle),
+            rotation: Quat::from_rotation_y(self.angle) * params.parent.rotation,
+            scale: Vec3::ONE,
+        }
+    }
+}
+
+/// Watches the line of sight of rigs with [`Occlusion`], using the [`CameraCollisionBackend`] `B`.
+///
+/// Adds [`DollyPlugin`] if it isn't yet. Can share its backend with a [`DollyCollisionPlugin`].
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # let mut app = App::new();
+/// app.add_plugins(DollyOcclusionPlugin::<AabbCollisionBackend>::default());
+/// ```
+pub struct DollyOcclusionPlugin<B: CameraCollisionBackend>(PhantomData<fn() -> B>);
+
+impl<B: CameraCollisionBackend> Default for DollyOcclusionPlugin<B> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<B: CameraCollisionBackend> Plugin for DollyOcclusionPlugin<B> {
+    fn build(&self, app: &mut App) {
+        crate::system::add_dolly_plugin(app)
+            .add_event::<TargetOccluded>()
+            .add_event::<TargetVisible>()
+            .add_systems(
+                PostUpdate,
+                update_occlusion::<B>
+                    .in_set(DollyPostProcessSet)
+                    .after(update_collision_arms::<B>)
+                    .before(crate::blend::update_rig_blends),
+            );
+    }
+}
+
+/// Position the rig looks at and the entity it belongs to, from the last look-at driver
+fn look_at_target(rig: &Rig) -> Option<(Vec3, Option<Entity>)> {
+    rig.drivers.iter().rev().find_map(|driver| {
+        let any = driver.as_any();
+        if let Some(look_at) = any.downcast_ref::<LookAt>() {
+            Some((look_at.target, None))
+        } else if let Some(look_at) = any.downcast_ref::<LookAtEntity>() {
+            Some((look_at.target()?, Some(look_at.entity)))
+        } else {
+            any.downcast_ref::<MovableLookAt>()?
+                .try_driver::<LookAt>()
+                .map(|look_at| (look_at.target, None))
+        }
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_occlusion<B: CameraCollisionBackend>(
+    backend: StaticSystemParam<B::Param>,
+    mut commands: Commands,
+    mut rigs: Query<(Entity, &mut Rig, &mut Occlusion)>,
+    rig_cameras: RigCameras,
+    marked: Query<Entity, With<Occluder>>,
+    mut occluded_events: EventWriter<TargetOccluded>,
+    mut visible_events: EventWriter<TargetVisible>,
+) {
+    let mut occluders = EntityHashSet::default();
+    for (entity, mut rig, mut occlusion) in &mut rigs {
+        let Some((look_at, followed)) = look_at_target(&rig) else {
+            continue;
+        };
+        let mut ignore = occlusion.ignore.clone();
+        ignore.push(entity);
+        ignore.extend(rig_cameras.cameras(entity));
+        ignore.extend(followed);
+        let radius = occlusion.radius;
+        let cast = |backend: &SystemParamItem<B::Param>, position: Vec3| {
+            let ray = position - look_at;
+            Dir3::new(ray).map_or(Vec::new(), |direction| {
+                B::cast_all(backend, look_at, direction, ray.length(), radius, &ignore)
+            })
+        };
+
+        let hits: Vec<Entity> = cast(&backend, rig.final_transform.translation)
+            .iter()
+            .map(|hit| hit.entity)
+            .collect();
+        if hits != occlusion.occluders {
+            match (occlusion.is_occluded(), hits.is_empty()) {
+                (false, false) => {
+                    occluded_events.send(TargetOccluded {
+                        rig: entity,
+                        occluders: hits.clone(),
+                    });
+                }
+                (true, true) => {
+                    visible_events.send(TargetVisible { rig: entity });
+                }
+                _ => (),
+            }
+            occlusion.occluders = hits;
+        }
+        if occlusion.mark_occluders {
+            occluders.extend(occlusion.occluders.iter().copied());
+        }
+
+        resolve_driver(&mut rig, |orbit: &mut OcclusionOrbit| {
+            let mut changed = orbit.target != Some(look_at);
+            orbit.target = Some(look_at);
+            let is_clear = |angle| cast(&backend, orbit.position_at(look_at, angle)).is_empty();
+            let target_angle = if orbit.target_angle != 0. && is_clear(0.) {
+                0.
+            } else if occlusion.is_occluded() && !is_clear(orbit.target_angle) {
+                orbit.find_clear(is_clear).unwrap_or(orbit.target_angle)
+            } else {
+                orbit.target_angle
+            };
+            changed |= target_angle != orbit.target_angle || orbit.angle != target_angle;
+            orbit.target_angle = target_angle;
+            changed
+        });
+    }
+
+    for entity in &marked {
+        if !occluders.remove(&entity) {
+            commands.entity(entity).remove::<Occluder>();
+        }
+    }
+    for entity in occluders {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.insert(Occluder);
+        }
+    }
+}
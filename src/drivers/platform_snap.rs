@@ -0,0 +1,210 @@
+use super::resolve_driver;
+use crate::{
+    prelude::*,
+    rig_2d::smoothing_t,
+    system::{entity_transform, EntityTransforms},
+};
+use bevy::prelude::*;
+
+/// Whether a platformer character stands on the ground and which way it faces,
+/// read by a [`PlatformSnap2d`] following it. Update it from the character controller.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PlatformerState {
+    pub grounded: bool,
+    /// Horizontal direction the character faces, -1 for left and 1 for right
+    pub facing: f32,
+}
+
+impl Default for PlatformerState {
+    fn default() -> Self {
+        Self {
+            grounded: true,
+            facing: 1.,
+        }
+    }
+}
+
+/// Follows a platformer character horizontally, but only re-anchors vertically when it lands.
+///
+/// While the character jumps or falls the camera keeps the height of the platform it left,
+/// until the character leaves the vertical window around it. Grounded state and facing come from
+/// the entity's [`PlatformerState`], without it the character counts as always grounded.
+/// With a forward focus the camera leads in the direction the character faces.
+/// Like [`FollowEntity`] it replaces the position of the previous drivers.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn rig(player: Entity) -> Rig2d {
+/// Rig2d::builder()
+///     .with(
+///         PlatformSnap2d::new(player)
+///             .with_vertical_window(100., 250.)
+///             .with_damping(0.2, 0.6)
+///             .with_forward_focus(150., 1.),
+///     )
+///     .build()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlatformSnap2d {
+    pub entity: Entity,
+    /// Added to the framed position
+    pub offset: Vec2,
+    /// How far the character can fall below the platform height without moving the camera,
+    /// negative windows are treated as 0
+    pub window_below: f32,
+    /// How far the character can jump above the platform height without moving the camera
+    pub window_above: f32,
+    /// Horizontal and vertical smoothness of following, like [`Smooth2d`]
+    pub damping: Vec2,
+    /// Distance the camera leads ahead of the character in the direction it faces
+    pub forward_focus: f32,
+    /// Smoothness of swinging the forward focus around when the character turns
+    pub focus_smoothness: f32,
+    /// See [`FollowEntity::use_global_transform`]
+    pub use_global_transform: bool,
+    /// A disabled driver keeps following the last position it read
+    pub enabled: bool,
+    target: Option<Vec2>,
+    state: PlatformerState,
+    anchor: Option<f32>,
+    focus: f32,
+    position: Option<Vec2>,
+    settling: bool,
+}
+
+impl PlatformSnap2d {
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            offset: Vec2::ZERO,
+            window_below: 100.,
+            window_above: 200.,
+            damping: Vec2::new(0.2, 0.5),
+            forward_focus: 0.,
+            focus_smoothness: 1.,
+            use_global_transform: false,
+            enabled: true,
+            target: None,
+            state: PlatformerState::default(),
+            anchor: None,
+            focus: 0.,
+            position: None,
+            settling: false,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_vertical_window(mut self, below: f32, above: f32) -> Self {
+        self.window_below = below;
+        self.window_above = above;
+        self
+    }
+
+    pub fn with_damping(mut self, horizontal: f32, vertical: f32) -> Self {
+        self.damping = Vec2::new(horizontal, vertical);
+        self
+    }
+
+    pub fn with_forward_focus(mut self, distance: f32, smoothness: f32) -> Self {
+        self.forward_focus = distance;
+        self.focus_smoothness = smoothness;
+        self
+    }
+
+    pub fn with_global_transform(mut self, use_global_transform: bool) -> Self {
+        self.use_global_transform = use_global_transform;
+        self
+    }
+
+    /// Height the camera is anchored at, the last platform landed on
+    pub fn anchor(&self) -> Option<f32> {
+        self.anchor
+    }
+
+    /// Last known position of the character
+    pub fn target(&self) -> Option<Vec2> {
+        self.target
+    }
+
+    /// Reads the character's position and state, returns whether the rig needs an update
+    fn resolve(&mut self, transforms: &EntityTransforms, states: &Query<&PlatformerState>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let Some(transform) = entity_transform(transforms, self.entity, self.use_global_transform)
+        else {
+            return self.settling;
+        };
+        let target = transform.translation.truncate();
+        let state = states.get(self.entity).copied().unwrap_or_default();
+
+        let anchor = match self.anchor {
+            // Keeps the height while airborne, until the character leaves the window
+            Some(anchor) if !state.grounded => anchor.clamp(
+                target.y - self.window_above.max(0.),
+                target.y + self.window_below.max(0.),
+            ),
+            _ => target.y,
+        };
+
+        let changed = self.target != Some(target)
+            || self.state != state
+            || self.anchor != Some(anchor)
+            || self.settling;
+        self.target = Some(target);
+        self.state = state;
+        self.anchor = Some(anchor);
+        changed
+    }
+}
+
+/// Exponential smoothing towards a value, like [`Smooth2d`]
+fn smooth(from: f32, to: f32, smoothness: f32, delta_time_seconds: f32) -> f32 {
+    from.lerp(to, smoothing_t(smoothness, delta_time_seconds))
+}
+
+impl RigDriver2d for PlatformSnap2d {
+    fn update(&mut self, parent: &Transform2d, delta_time_seconds: f32) -> Transform2d {
+        let (Some(target), Some(anchor)) = (self.target, self.anchor) else {
+            return *parent;
+        };
+
+        let focus = self.state.facing.clamp(-1., 1.) * self.forward_focus;
+        self.focus = smooth(self.focus, focus, self.focus_smoothness, delta_time_seconds);
+        let desired = Vec2::new(target.x + self.focus, anchor) + self.offset;
+        let position = match self.position {
+            Some(position) => Vec2::new(
+                smooth(position.x, desired.x, self.damping.x, delta_time_seconds),
+                smooth(position.y, desired.y, self.damping.y, delta_time_seconds),
+            ),
+            // Starts at the character
+            None => desired,
+        };
+        self.position = Some(position);
+        self.settling =
+            position.distance_squared(desired) > 1e-6 || (self.focus - focus).abs() > 1e-3;
+
+        Transform2d {
+            position,
+            ..*parent
+        }
+    }
+}
+
+/// Reads the position and state of the characters followed by [`PlatformSnap2d`] drivers
+pub(crate) fn resolve_platform_snaps(
+    mut rigs: Query<&mut Rig2d>,
+    transforms: EntityTransforms,
+    states: Query<&PlatformerState>,
+) {
+    for mut rig in &mut rigs {
+        resolve_driver(&mut rig, |snap: &mut PlatformSnap2d| {
+            snap.resolve(&transforms, &states)
+        });
+    }
+}
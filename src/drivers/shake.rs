@@ -0,0 +1,225 @@
+use super::resolve_driver;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Adds trauma to the [`Shake`] drivers of a rig, or of every rig.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// fn explode(mut trauma: EventWriter<AddTrauma>) {
+///     trauma.send(AddTrauma::all(0.6));
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct AddTrauma {
+    /// Entity of the rig, `None` shakes every rig
+    pub rig: Option<Entity>,
+    pub trauma: f32,
+}
+
+impl AddTrauma {
+    pub fn to(rig: Entity, trauma: f32) -> Self {
+        Self {
+            rig: Some(rig),
+            trauma,
+        }
+    }
+
+    pub fn all(trauma: f32) -> Self {
+        Self { rig: None, trauma }
+    }
+}
+
+/// Shakes the output of the previous drivers, in their local space.
+///
+/// The strength of the shake is the accumulated trauma, between 0 and 1, raised to
+/// [`Shake::exponent`]. Trauma decays linearly over time. The motion is smooth gradient noise,
+/// the same for the same seed and frame times. Add it after any `Smooth` so the shake isn't
+/// smoothed away. Works in a [`Rig`] and in a [`Rig2d`], where it moves along X and Y and rolls.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # let rig =
+/// Rig::builder()
+///     .with(Position::new(Vec3::Y))
+///     .with(Smooth::new_position(1.))
+///     .with(Shake::new().with_seed(7))
+///     .build()
+/// # ;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shake {
+    /// Largest offset along each local axis, at full trauma
+    pub translation_amplitude: Vec3,
+    /// Oscillations per second along each local axis
+    pub translation_frequency: Vec3,
+    /// Largest pitch, yaw and roll in radians, at full trauma
+    pub rotation_amplitude: Vec3,
+    /// Oscillations per second of pitch, yaw and roll
+    pub rotation_frequency: Vec3,
+    /// Trauma lost per second
+    pub decay: f32,
+    /// Shake strength is `trauma.powf(exponent)`, so light hits barely shake
+    pub exponent: f32,
+    pub seed: u32,
+    trauma: f32,
+    time: f32,
+}
+
+impl Default for Shake {
+    fn default() -> Self {
+        Self {
+            translation_amplitude: Vec3::splat(0.2),
+            translation_frequency: Vec3::splat(12.),
+            rotation_amplitude: Vec3::new(2., 2., 4.).map(f32::to_radians),
+            rotation_frequency: Vec3::splat(12.),
+            decay: 1.,
+            exponent: 2.,
+            seed: 0,
+            trauma: 0.,
+            time: 0.,
+        }
+    }
+}
+
+impl Shake {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_translation(mut self, amplitude: Vec3, frequency: Vec3) -> Self {
+        self.translation_amplitude = amplitude;
+        self.translation_frequency = frequency;
+        self
+    }
+
+    /// Amplitude of pitch, yaw and roll in degrees
+    pub fn with_rotation_degrees(mut self, amplitude: Vec3, frequency: Vec3) -> Self {
+        self.rotation_amplitude = amplitude.map(f32::to_radians);
+        self.rotation_frequency = frequency;
+        self
+    }
+
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    pub fn with_exponent(mut self, exponent: f32) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    /// Adds trauma, capped at 1
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).clamp(0., 1.);
+    }
+
+    /// Advances time and decays trauma, returns the translation and pitch, yaw and roll offsets
+    fn advance(&mut self, delta_time_seconds: f32) -> (Vec3, Vec3) {
+        self.time += delta_time_seconds;
+        self.trauma = (self.trauma - self.decay * delta_time_seconds).max(0.);
+        let strength = self.trauma.powf(self.exponent);
+        if strength == 0. {
+            return (Vec3::ZERO, Vec3::ZERO);
+        }
+
+        let channels = |first: u32, frequency: Vec3| {
+            Vec3::new(
+                noise(self.seed, first, self.time * frequency.x),
+                noise(self.seed, first + 1, self.time * frequency.y),
+                noise(self.seed, first + 2, self.time * frequency.z),
+            )
+        };
+        (
+            channels(0, self.translation_frequency) * self.translation_amplitude * strength,
+            channels(3, self.rotation_frequency) * self.rotation_amplitude * strength,
+        )
+    }
+}
+
+/// Pseudo random gradient between -1 and 1 at a lattice point of a noise channel
+fn gradient(seed: u32, channel: u32, x: i32) -> f32 {
+    let mut h = seed
+        .wrapping_mul(0x9E37_79B9)
+        .wrapping_add(channel.wrapping_mul(0x85EB_CA6B))
+        .wrapping_add((x as u32).wrapping_mul(0xC2B2_AE35));
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7FEB_352D);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846C_A68B);
+    h ^= h >> 16;
+    h as f32 / u32::MAX as f32 * 2. - 1.
+}
+
+/// One dimensional gradient noise, roughly between -1 and 1
+fn noise(seed: u32, channel: u32, x: f32) -> f32 {
+    let cell = x.floor();
+    let t = x - cell;
+    let fade = t * t * t * (t * (t * 6. - 15.) + 10.);
+    let a = gradient(seed, channel, cell as i32) * t;
+    let b = gradient(seed, channel, cell as i32 + 1) * (t - 1.);
+    // Gradient noise peaks at half the gradient
+    a.lerp(b, fade) * 2.
+}
+
+impl RigDriver for Shake {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        let (translation, rotation) = self.advance(params.delta_time_seconds);
+        Transform {
+            translation: params.parent.translation + params.parent.rotation * translation,
+            rotation: params.parent.rotation
+                * Quat::from_euler(EulerRot::YXZ, rotation.y, rotation.x, rotation.z),
+            scale: Vec3::ONE,
+        }
+    }
+}
+
+impl RigDriver2d for Shake {
+    fn update(&mut self, parent: &Transform2d, delta_time_seconds: f32) -> Transform2d {
+        let (translation, rotation) = self.advance(delta_time_seconds);
+        Transform2d {
+            position: parent.position + Rot2::radians(parent.roll) * translation.truncate(),
+            roll: parent.roll + rotation.z,
+            ..*parent
+        }
+    }
+}
+
+/// Adds the trauma of [`AddTrauma`] events and keeps shaking rigs updating until it's gone
+pub(crate) fn apply_trauma(
+    mut events: EventReader<AddTrauma>,
+    mut rigs: Query<(Entity, &mut Rig)>,
+    mut rigs_2d: Query<(Entity, &mut Rig2d)>,
+) {
+    let events: Vec<AddTrauma> = events.read().copied().collect();
+    let trauma_for = |entity| {
+        events
+            .iter()
+            .filter(|event| event.rig.is_none_or(|rig| rig == entity))
+            .map(|event| event.trauma)
+            .sum::<f32>()
+    };
+
+    let shake = |trauma| {
+        move |shake: &mut Shake| {
+            shake.add_trauma(trauma);
+            shake.trauma() > 0.
+        }
+    };
+    for (entity, mut rig) in &mut rigs {
+        resolve_driver(&mut rig, shake(trauma_for(entity)));
+    }
+    for (entity, mut rig) in &mut rigs_2d {
+        resolve_driver(&mut rig, shake(trauma_for(entity)));
+    }
+}
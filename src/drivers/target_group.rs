@@ -0,0 +1,279 @@
+use std::cell::OnceCell;
+
+use super::{resolve_driver, view::CameraViews};
+use crate::{
+    ordered,
+    prelude::*,
+    system::{entity_transform, EntityTransforms, RigCameras},
+};
+use bevy::prelude::*;
+
+/// An entity framed by a [`TargetGroup`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupTarget {
+    pub entity: Entity,
+    /// Pull of the target on the center of the frame, targets with a weight of 0 are ignored
+    pub weight: f32,
+    /// Radius around the target's origin that has to stay in frame
+    pub radius: f32,
+    position: Option<Vec3>,
+}
+
+/// Moves and zooms the camera so every target of the group stays in frame.
+///
+/// The camera is centered on the weighted average of the targets. Perspective cameras are moved
+/// back along the rotation of the previous drivers, orthographic cameras and [`Rig2d`]s are zoomed.
+/// Target positions and the view of the camera the rig drives are read by [`DollyPlugin`]
+/// every frame.
+/// Targets that are children of another entity lag a frame behind, as their global transform
+/// isn't propagated yet. Despawned targets are dropped from the group.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn rig(player_one: Entity, player_two: Entity) -> Rig {
+/// Rig::builder()
+///     .with(YawPitch::new().pitch_degrees(-30.))
+///     .with(
+///         TargetGroup::new()
+///             .with_target(player_one, 1., 1.)
+///             .with_target(player_two, 1., 1.)
+///             .with_padding(2.),
+///     )
+///     .with(Smooth::new_position(1.))
+///     .build()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetGroup {
+    pub targets: Vec<GroupTarget>,
+    /// Extra space around the targets, in world units
+    pub padding: f32,
+    /// Closest and furthest a perspective camera gets to the center of the group, in either order
+    pub distance_limits: (f32, f32),
+    /// Zoom limits of orthographic cameras and [`Rig2d`]s, in either order
+    pub zoom_limits: (f32, f32),
+    pub view: CameraView,
+    orthographic_scale: Option<f32>,
+}
+
+impl Default for TargetGroup {
+    fn default() -> Self {
+        Self {
+            targets: Vec::new(),
+            padding: 0.,
+            distance_limits: (0., f32::INFINITY),
+            zoom_limits: (0., f32::INFINITY),
+            view: CameraView::default(),
+            orthographic_scale: None,
+        }
+    }
+}
+
+impl TargetGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_target(mut self, entity: Entity, weight: f32, radius: f32) -> Self {
+        self.add_target(entity, weight, radius);
+        self
+    }
+
+    pub fn with_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn with_distance_limits(mut self, min: f32, max: f32) -> Self {
+        self.distance_limits = (min, max);
+        self
+    }
+
+    pub fn with_zoom_limits(mut self, min: f32, max: f32) -> Self {
+        self.zoom_limits = (min, max);
+        self
+    }
+
+    /// Adds a target, or updates its weight and radius if it is already in the group
+    pub fn add_target(&mut self, entity: Entity, weight: f32, radius: f32) {
+        match self.targets.iter_mut().find(|t| t.entity == entity) {
+            Some(target) => {
+                target.weight = weight;
+                target.radius = radius;
+            }
+            None => self.targets.push(GroupTarget {
+                entity,
+                weight,
+                radius,
+                position: None,
+            }),
+        }
+    }
+
+    pub fn remove_target(&mut self, entity: Entity) {
+        self.targets.retain(|t| t.entity != entity);
+    }
+
+    /// Orthographic scale the group needs on a 3D camera, written to the camera by [`DollyPlugin`]
+    pub fn orthographic_scale(&self) -> Option<f32> {
+        self.orthographic_scale
+    }
+
+    /// Targets with a known position and a weight above 0, with their positions
+    fn framed(&self) -> impl Iterator<Item = (&GroupTarget, Vec3)> {
+        self.targets
+            .iter()
+            .filter(|t| t.weight > 0.)
+            .filter_map(|t| t.position.map(|p| (t, p)))
+    }
+
+    fn weighted_center(&self) -> Option<Vec3> {
+        let (sum, weight) = self
+            .framed()
+            .fold((Vec3::ZERO, 0.), |(sum, weight), (t, p)| {
+                (sum + p * t.weight, weight + t.weight)
+            });
+        (weight > 0.).then(|| sum / weight)
+    }
+
+    /// Half size of the frame around the center, along the camera's right and up axes
+    fn half_extents(&self, center: Vec3, right: Vec3, up: Vec3) -> Vec2 {
+        self.framed().fold(Vec2::ZERO, |extents, (t, p)| {
+            let offset = p - center;
+            extents.max(Vec2::new(
+                offset.dot(right).abs() + t.radius,
+                offset.dot(up).abs() + t.radius,
+            ))
+        }) + Vec2::splat(self.padding)
+    }
+
+    /// Zoom that fits the half extents in the orthographic view
+    fn zoom_to_fit(&self, half_extents: Vec2) -> Option<f32> {
+        let CameraView::Orthographic { area, .. } = self.view else {
+            return None;
+        };
+        // The smallest distance from the camera to an edge of the view, at a scale of 1
+        let half_view = (-area.min).min(area.max);
+        let zoom = (half_view / half_extents.max(Vec2::splat(f32::EPSILON))).min_element();
+        let (min, max) = ordered(self.zoom_limits.0, self.zoom_limits.1);
+        (zoom > 0.).then(|| zoom.clamp(min, max))
+    }
+}
+
+impl RigDriver for TargetGroup {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        let mut transform = *params.parent;
+        let Some(center) = self.weighted_center() else {
+            return transform;
+        };
+
+        let back = transform.back();
+        let half_extents = self.half_extents(center, *transform.right(), *transform.up());
+        let radius = half_extents.length();
+        let distance = match self.view {
+            CameraView::Perspective { fov, aspect, .. } => {
+                let half_fov = fov * 0.5;
+                let half_fov_horizontal = (half_fov.tan() * aspect).atan();
+                radius / half_fov.min(half_fov_horizontal).sin().max(f32::EPSILON)
+            }
+            // Far enough to have the whole group in front of the camera
+            CameraView::Orthographic { .. } => {
+                self.orthographic_scale = self.zoom_to_fit(half_extents).map(|zoom| 1. / zoom);
+                radius
+            }
+        };
+        let (min, max) = ordered(self.distance_limits.0, self.distance_limits.1);
+        transform.translation = center + back * distance.clamp(min, max);
+        transform
+    }
+}
+
+impl RigDriver2d for TargetGroup {
+    fn update(&mut self, parent: &Transform2d, _: f32) -> Transform2d {
+        let Some(center) = self.weighted_center() else {
+            return *parent;
+        };
+        let rotation = Quat::from_rotation_z(parent.roll);
+        let half_extents = self.half_extents(center, rotation * Vec3::X, rotation * Vec3::Y);
+        Transform2d {
+            position: center.truncate(),
+            zoom: self.zoom_to_fit(half_extents).unwrap_or(parent.zoom),
+            ..*parent
+        }
+    }
+}
+
+/// Copies the target positions and the camera view into every [`TargetGroup`]
+#[allow(clippy::type_complexity)]
+pub(crate) fn resolve_target_groups(
+    mut rigs: Query<(Entity, Option<&mut Rig>, Option<&mut Rig2d>), Or<(With<Rig>, With<Rig2d>)>>,
+    cameras: CameraViews,
+    transforms: EntityTransforms,
+) {
+    let resolve = |group: &TargetGroup, view: Option<CameraView>| {
+        let mut resolved = group.clone();
+        resolved.targets.retain(|t| transforms.contains(t.entity));
+        for target in &mut resolved.targets {
+            target.position =
+                entity_transform(&transforms, target.entity, false).map(|t| t.translation);
+        }
+        if let Some(view) = view {
+            resolved.view = view;
+        }
+        resolved
+    };
+
+    for (entity, rig, rig_2d) in &mut rigs {
+        // Only looked up for rigs with a target group
+        let view = OnceCell::new();
+        let update = |group: &mut TargetGroup| {
+            let resolved = resolve(group, *view.get_or_init(|| cameras.of(entity)));
+            let changed = *group != resolved;
+            *group = resolved;
+            changed
+        };
+        if let Some(mut rig) = rig {
+            resolve_driver(&mut rig, update);
+        }
+        if let Some(mut rig) = rig_2d {
+            resolve_driver(&mut rig, update);
+        }
+    }
+}
+
+/// Writes the orthographic scale of [`TargetGroup`]s to the 3D cameras their rigs drive
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_target_group_scale(
+    rigs: Query<(Entity, &Rig)>,
+    rig_cameras: RigCameras,
+    mut cameras: Query<
+        (Option<&mut Projection>, Option<&mut OrthographicProjection>),
+        With<Camera>,
+    >,
+) {
+    for (entity, rig) in &rigs {
+        let Some(scale) = rig
+            .try_driver::<TargetGroup>()
+            .and_then(|group| group.orthographic_scale())
+        else {
+            continue;
+        };
+        for camera in rig_cameras.cameras(entity) {
+            let Ok((projection, orthographic)) = cameras.get_mut(camera) else {
+                continue;
+            };
+            match (projection, orthographic) {
+                (Some(mut projection), _) => {
+                    // Checked first so perspective cameras aren't flagged as changed
+                    if matches!(*projection, Projection::Orthographic(_)) {
+                        if let Projection::Orthographic(o) = &mut *projection {
+                            o.scale = scale;
+                        }
+                    }
+                }
+                (None, Some(mut o)) => o.scale = scale,
+                (None, None) => (),
+            }
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use super::resolve_driver;
+use crate::{
+    prelude::*,
+    system::{entity_transform, EntityTransforms},
+};
+use bevy::prelude::*;
+
+/// How the control points of a [`DollyTrackPath`] shape the track
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrackKind {
+    /// Passes through every point
+    #[default]
+    CatmullRom,
+    /// Cubic Bézier segments, every segment is an end point followed by two handles:
+    /// `[start, handle, handle, point, handle, handle, end]`
+    Bezier,
+}
+
+/// The rails of a [`DollyTrack`], control points in the space of the entity it is on.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn setup(mut commands: Commands) {
+/// let path = commands
+///     .spawn((
+///         DollyTrackPath::catmull_rom(vec![
+///             Vec3::new(-8., 2., 8.),
+///             Vec3::new(0., 3., 10.),
+///             Vec3::new(8., 2., 8.),
+///         ]),
+///         Transform::default(),
+///     ))
+///     .id();
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+#[require(Transform)]
+pub struct DollyTrackPath {
+    pub kind: TrackKind,
+    pub points: Vec<Vec3>,
+    /// Connects the last point back to the first
+    pub closed: bool,
+}
+
+impl DollyTrackPath {
+    pub fn catmull_rom(points: Vec<Vec3>) -> Self {
+        Self {
+            kind: TrackKind::CatmullRom,
+            points,
+            closed: false,
+        }
+    }
+
+    pub fn bezier(points: Vec<Vec3>) -> Self {
+        Self {
+            kind: TrackKind::Bezier,
+            points,
+            closed: false,
+        }
+    }
+
+    pub fn with_closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    /// The curve through the points, `None` if there aren't enough of them
+    fn to_curve(&self) -> Option<CubicCurve<Vec3>> {
+        match self.kind {
+            TrackKind::CatmullRom if self.closed => {
+                CubicCardinalSpline::new_catmull_rom(self.points.as_slice())
+                    .to_curve_cyclic()
+                    .ok()
+            }
+            TrackKind::CatmullRom => CubicCardinalSpline::new_catmull_rom(self.points.as_slice())
+                .to_curve()
+                .ok(),
+            TrackKind::Bezier => {
+                let mut points = self.points.clone();
+                if self.closed {
+                    points.extend(self.points.first());
+                }
+                let segments: Vec<[Vec3; 4]> = (0..points.len().saturating_sub(1) / 3)
+                    .map(|i| {
+                        [
+                            points[i * 3],
+                            points[i * 3 + 1],
+                            points[i * 3 + 2],
+                            points[i * 3 + 3],
+                        ]
+                    })
+                    .collect();
+                CubicBezier::new(segments).to_curve().ok()
+            }
+        }
+    }
+}
+
+/// A curve with a table of distances along it
+#[derive(Debug, Clone, PartialEq)]
+struct TrackCurve {
+    curve: CubicCurve<Vec3>,
+    closed: bool,
+    /// Distance from the start at every sample
+    lengths: Vec<f32>,
+}
+
+impl TrackCurve {
+    const SAMPLES_PER_SEGMENT: usize = 32;
+
+    fn new(curve: CubicCurve<Vec3>, closed: bool) -> Self {
+        let samples = curve.segments().len() * Self::SAMPLES_PER_SEGMENT;
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut length = 0.;
+        let mut previous = curve.position(0.);
+        lengths.push(0.);
+        for position in curve.iter_positions(samples).skip(1) {
+            length += previous.distance(position);
+            lengths.push(length);
+            previous = position;
+        }
+        Self {
+            curve,
+            closed,
+            lengths,
+        }
+    }
+
+    fn length(&self) -> f32 {
+        self.lengths.last().copied().unwrap_or(0.)
+    }
+
+    /// Curve parameter at a distance from the start, wrapped around closed tracks
+    fn parameter_at(&self, distance: f32) -> f32 {
+        let length = self.length();
+        let distance = if self.closed && length > 0. {
+            distance.rem_euclid(length)
+        } else {
+            distance.clamp(0., length)
+        };
+        let next = self
+            .lengths
+            .partition_point(|&l| l < distance)
+            .clamp(1, self.lengths.len() - 1);
+        let (start, end) = (self.lengths[next - 1], self.lengths[next]);
+        let t = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            0.
+        };
+        ((next - 1) as f32 + t) / Self::SAMPLES_PER_SEGMENT as f32
+    }
+
+    /// Distance from the start of the point on the track closest to `point`
+    fn closest_distance(&self, point: Vec3) -> f32 {
+        let positions: Vec<Vec3> = self.curve.iter_positions(self.lengths.len() - 1).collect();
+        positions
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let (a, b) = (pair[0], pair[1]);
+                let ab = b - a;
+                let t = ((point - a).dot(ab) / ab.length_squared().max(f32::EPSILON)).clamp(0., 1.);
+                let closest = a + ab * t;
+                (
+                    closest.distance_squared(point),
+                    self.lengths[i].lerp(self.lengths[i + 1], t),
+                )
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map_or(0., |(_, distance)| distance)
+    }
+}
+
+/// Where along its track a [`DollyTrack`] places the rig
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackPosition {
+    /// Distance from the start, in world units
+    Distance(f32),
+    /// From 0 at the start to 1 at the end
+    Normalized(f32),
+    /// The point closest to an entity, ie. the player on an on-rails section
+    Closest(Entity),
+}
+
+/// Moves the rig along the rails of a [`DollyTrackPath`].
+///
+/// The path and the entity of [`TrackPosition::Closest`] are read by [`DollyPlugin`] every frame
+/// before the rig is evaluated. Animate [`DollyTrack::position`] for cutscenes,
+/// or let the rig follow an entity along the track.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # fn rig(path: Entity, player: Entity) -> Rig {
+/// Rig::builder()
+///     .with(DollyTrack::new(path).closest_to(player))
+///     .with(Smooth::new_position(0.5))
+///     .with(LookAtEntity::new(player))
+///     .build()
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DollyTrack {
+    /// Entity with the [`DollyTrackPath`]
+    pub path: Entity,
+    pub position: TrackPosition,
+    /// Face along the track instead of keeping the rotation of the previous drivers
+    pub follow_tangent: bool,
+    points: Vec<Vec3>,
+    curve: Option<TrackCurve>,
+    target: Option<Vec3>,
+    distance: f32,
+}
+
+impl DollyTrack {
+    pub fn new(path: Entity) -> Self {
+        Self {
+            path,
+            position: TrackPosition::Distance(0.),
+            follow_tangent: false,
+            points: Vec::new(),
+            curve: None,
+            target: None,
+            distance: 0.,
+        }
+    }
+
+    pub fn at_distance(mut self, distance: f32) -> Self {
+        self.position = TrackPosition::Distance(distance);
+        self
+    }
+
+    pub fn at_normalized(mut self, t: f32) -> Self {
+        self.position = TrackPosition::Normalized(t);
+        self
+    }
+
+    pub fn closest_to(mut self, entity: Entity) -> Self {
+        self.position = TrackPosition::Closest(entity);
+        self
+    }
+
+    pub fn with_tangent_rotation(mut self, follow_tangent: bool) -> Self {
+        self.follow_tangent = follow_tangent;
+        self
+    }
+
+    /// Length of the track, 0 until the path is read
+    pub fn length(&self) -> f32 {
+        self.curve.as_ref().map_or(0., TrackCurve::length)
+    }
+
+    /// Distance along the track the rig was last placed at
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// Copies the path in world space and the followed entity's position,
+    /// returns whether the output of the driver changed
+    fn resolve(&mut self, path: Option<(&DollyTrackPath, Mat4)>, target: Option<Vec3>) -> bool {
+        let mut changed = self.target != target;
+        self.target = target;
+        if let Some((path, to_world)) = path {
+            let points: Vec<Vec3> = path
+                .points
+                .iter()
+                .map(|p| to_world.transform_point3(*p))
+                .collect();
+            let closed = self.curve.as_ref().is_some_and(|c| c.closed);
+            if points != self.points || closed != path.closed || self.curve.is_none() {
+                let world = DollyTrackPath {
+                    points: points.clone(),
+                    ..path.clone()
+                };
+                self.curve = world
+                    .to_curve()
+                    .map(|curve| TrackCurve::new(curve, path.closed));
+                self.points = points;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+impl RigDriver for DollyTrack {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        let Some(curve) = &self.curve else {
+            return *params.parent;
+        };
+        self.distance = match self.position {
+            TrackPosition::Distance(distance) => distance,
+            TrackPosition::Normalized(t) => t * curve.length(),
+            TrackPosition::Closest(_) => self
+                .target
+                .map_or(self.distance, |target| curve.closest_distance(target)),
+        };
+
+        let t = curve.parameter_at(self.distance);
+        let tangent = curve.curve.velocity(t);
+        Transform {
+            translation: curve.curve.position(t),
+            rotation: if self.follow_tangent && tangent != Vec3::ZERO {
+                Transform::default().looking_to(tangent, Vec3::Y).rotation
+            } else {
+                params.parent.rotation
+            },
+            scale: Vec3::ONE,
+        }
+    }
+}
+
+/// Reads the paths and followed entities of every [`DollyTrack`]
+pub(crate) fn resolve_dolly_tracks(
+    mut rigs: Query<&mut Rig>,
+    paths: Query<&DollyTrackPath>,
+    transforms: EntityTransforms,
+) {
+    let to_world =
+        |entity| entity_transform(&transforms, entity, false).map(|t| t.compute_matrix());
+
+    for mut rig in &mut rigs {
+        resolve_driver(&mut rig, |track: &mut DollyTrack| {
+            let path = paths.get(track.path).ok().zip(to_world(track.path));
+            let target = match track.position {
+                TrackPosition::Closest(entity) => to_world(entity).map(|m| m.w_axis.truncate()),
+                _ => None,
+            };
+            track.resolve(path, target)
+        });
+    }
+}
@@ -0,0 +1,104 @@
+use crate::system::RigCameras;
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+/// What a camera sees, read from its projection by [`DollyPlugin`](crate::system::DollyPlugin)
+/// every frame for drivers that need to know how much of the world is in view.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CameraView {
+    /// Visible area around the camera at an orthographic scale of 1, and the current scale
+    Orthographic {
+        area: Rect,
+        scale: f32,
+    },
+    Perspective {
+        fov: f32,
+        aspect: f32,
+        near: f32,
+    },
+}
+
+impl Default for CameraView {
+    fn default() -> Self {
+        CameraView::Orthographic {
+            area: Rect::default(),
+            scale: 1.,
+        }
+    }
+}
+
+impl CameraView {
+    /// Reads the view of a camera from either of its projection components
+    pub fn from_projection(
+        projection: Option<&Projection>,
+        orthographic: Option<&OrthographicProjection>,
+    ) -> Option<Self> {
+        let orthographic_view = |o: &OrthographicProjection| {
+            let scale = o.scale.max(f32::EPSILON);
+            CameraView::Orthographic {
+                area: Rect::from_corners(o.area.min / scale, o.area.max / scale),
+                scale: o.scale,
+            }
+        };
+        match (projection, orthographic) {
+            (Some(Projection::Perspective(p)), _) => Some(CameraView::Perspective {
+                fov: p.fov,
+                aspect: p.aspect_ratio,
+                near: p.near,
+            }),
+            (Some(Projection::Orthographic(o)), _) | (None, Some(o)) => Some(orthographic_view(o)),
+            (None, None) => None,
+        }
+    }
+
+    /// Visible area around the camera in its view plane, at a distance from the camera for perspective.
+    /// `scale` replaces the orthographic scale of the projection, for rigs that set it themselves.
+    pub fn visible_area(&self, scale: Option<f32>, distance: f32) -> Rect {
+        match *self {
+            CameraView::Orthographic {
+                area,
+                scale: current,
+            } => {
+                let scale = scale.unwrap_or(current);
+                Rect::from_corners(area.min * scale, area.max * scale)
+            }
+            CameraView::Perspective { fov, aspect, .. } => {
+                let half_height = (fov * 0.5).tan() * distance.abs();
+                Rect::from_center_half_size(
+                    Vec2::ZERO,
+                    Vec2::new(half_height * aspect, half_height),
+                )
+            }
+        }
+    }
+}
+
+/// The projections of every camera, to look up the view of the camera a rig drives
+#[derive(SystemParam)]
+pub(crate) struct CameraViews<'w, 's> {
+    rig_cameras: RigCameras<'w, 's>,
+    projections: Query<
+        'w,
+        's,
+        (
+            Option<&'static Projection>,
+            Option<&'static OrthographicProjection>,
+        ),
+        With<Camera>,
+    >,
+}
+
+impl CameraViews<'_, '_> {
+    /// View of the camera a rig drives, see [`RigCameras`]
+    pub(crate) fn of(&self, rig: Entity) -> Option<CameraView> {
+        let view = self.rig_cameras.camera(rig).and_then(|camera| {
+            let (projection, orthographic) = self.projections.get(camera).ok()?;
+            CameraView::from_projection(projection, orthographic)
+        });
+        if view.is_none() && !self.rig_cameras.is_waiting(rig) {
+            warn_once!(
+                "Rig {rig} drives no camera with a projection, its drivers can't tell what is in view"
+            );
+        }
+        view
+    }
+}
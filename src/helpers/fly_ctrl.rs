@@ -0,0 +1,244 @@
+use bevy::{
+    prelude::*,
+    window::{CursorGrabMode, PrimaryWindow},
+};
+use leafwing_input_manager::prelude::*;
+
+use crate::{ordered, prelude::*};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct DollyFlyCtrlSet;
+
+/// This plugin flies rigs around like the camera of an editor.
+/// Add a [`DollyFlyCtrlConfig`] to an entity with a [`Rig`] made of an [`Fpv`] driver:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_dolly::prelude::*;
+///
+/// #[derive(Component)]
+/// struct MainCamera;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, DollyPlugin, DollyFlyCtrl))
+///         .add_dolly_component(MainCamera)
+///         .add_systems(Startup, setup)
+///         .run();
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     let transform = Transform::from_xyz(2., 2., 5.).looking_at(Vec3::ZERO, Vec3::Y);
+///     commands.spawn((
+///         MainCamera,
+///         Rig::builder()
+///             .with(Fpv::from_position_target(transform))
+///             .build(),
+///         DollyFlyCtrlConfig::default(),
+///         Camera3d::default(),
+///         transform,
+///     ));
+/// }
+/// ```
+/// Fly with W, A, S, D, E and Q while holding the right mouse button, scroll to change the speed.
+/// Gamepad sticks work without holding anything. Insert an `InputMap<FlyAction>` alongside
+/// the config for other bindings.
+pub struct DollyFlyCtrl;
+impl Plugin for DollyFlyCtrl {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InputManagerPlugin::<FlyAction>::default());
+        app.add_systems(
+            Update,
+            (dolly_fly_ctrl_input_setup, dolly_fly_ctrl_update)
+                .chain()
+                .in_set(DollyFlyCtrlSet),
+        );
+    }
+}
+
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+pub enum FlyAction {
+    Forward,
+    Backward,
+    Left,
+    Right,
+    Up,
+    Down,
+    /// Multiplies the speed by [`DollyFlyCtrlConfig::boost_mult`] while held
+    Boost,
+    /// Multiplies the speed by [`DollyFlyCtrlConfig::slow_mult`] while held
+    Slow,
+    /// Looks around and flies while held, see [`DollyFlyCtrlConfig::look_button`]
+    Look,
+    #[actionlike(DualAxis)]
+    Move,
+    #[actionlike(DualAxis)]
+    LookMouse,
+    #[actionlike(DualAxis)]
+    LookStick,
+    #[actionlike(Axis)]
+    Speed,
+}
+
+/// Settings of the [`DollyFlyCtrl`] of a rig
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct DollyFlyCtrlConfig {
+    pub enabled: bool,
+    /// Units per second
+    pub speed: f32,
+    /// Limits of the speed set by scrolling, in either order
+    pub min_speed: f32,
+    pub max_speed: f32,
+    /// Speed change per line scrolled, 0.2 flies 20% faster
+    pub speed_sensitivity: f32,
+    pub boost_mult: f32,
+    pub slow_mult: f32,
+    /// Degrees of yaw and pitch per unit of mouse motion
+    pub sensitivity: Vec2,
+    /// Degrees of yaw and pitch per second with the stick fully tilted
+    pub stick_sensitivity: Vec2,
+    /// Only look around and fly with the keyboard while this button is held.
+    /// With `None` the rig always follows the mouse, ie. with a grabbed cursor.
+    pub look_button: Option<MouseButton>,
+    /// Hide and confine the cursor while the look button is held
+    pub grab_cursor: bool,
+    /// Fly horizontally and go up and down along the world's Y axis,
+    /// instead of along the axes of the camera
+    pub world_vertical: bool,
+}
+
+impl Default for DollyFlyCtrlConfig {
+    fn default() -> Self {
+        DollyFlyCtrlConfig {
+            enabled: true,
+            speed: 10.,
+            min_speed: 0.1,
+            max_speed: 1000.,
+            speed_sensitivity: 0.2,
+            boost_mult: 5.,
+            slow_mult: 0.2,
+            sensitivity: Vec2::splat(0.1),
+            stick_sensitivity: Vec2::splat(120.),
+            look_button: Some(MouseButton::Right),
+            grab_cursor: true,
+            world_vertical: false,
+        }
+    }
+}
+
+impl DollyFlyCtrlConfig {
+    /// W, A, S, D, E and Q or Space, Shift to boost, Alt to slow down,
+    /// the look button, the mouse, the scroll wheel and the gamepad
+    pub fn input_map(&self) -> InputMap<FlyAction> {
+        use FlyAction::*;
+        let input_map = InputMap::default()
+            .with(Forward, KeyCode::KeyW)
+            .with(Backward, KeyCode::KeyS)
+            .with(Left, KeyCode::KeyA)
+            .with(Right, KeyCode::KeyD)
+            .with(Up, KeyCode::KeyE)
+            .with(Up, KeyCode::Space)
+            .with(Up, GamepadButton::RightTrigger2)
+            .with(Down, KeyCode::KeyQ)
+            .with(Down, GamepadButton::LeftTrigger2)
+            .with(Boost, KeyCode::ShiftLeft)
+            .with(Boost, GamepadButton::LeftThumb)
+            .with(Slow, KeyCode::AltLeft)
+            .with(Slow, GamepadButton::RightThumb)
+            .with_dual_axis(Move, GamepadStick::LEFT)
+            .with_dual_axis(LookMouse, MouseMove::default())
+            .with_dual_axis(LookStick, GamepadStick::RIGHT)
+            .with_axis(Speed, MouseScrollAxis::Y);
+
+        match self.look_button {
+            Some(button) => input_map.with(Look, button),
+            None => input_map,
+        }
+    }
+}
+
+fn dolly_fly_ctrl_input_setup(
+    mut commands: Commands,
+    ctrls: Query<(Entity, &DollyFlyCtrlConfig), Without<ActionState<FlyAction>>>,
+) {
+    for (entity, config) in &ctrls {
+        commands.entity(entity).insert(InputManagerBundle {
+            input_map: config.input_map(),
+            action_state: ActionState::default(),
+        });
+    }
+}
+
+fn dolly_fly_ctrl_update(
+    time: Res<Time>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut ctrls: Query<(&mut DollyFlyCtrlConfig, &ActionState<FlyAction>, &mut Rig)>,
+) {
+    let delta_time_sec = time.delta_secs();
+
+    for (mut config, actions, mut rig) in &mut ctrls {
+        if !config.enabled {
+            continue;
+        }
+
+        if config.grab_cursor && config.look_button.is_some() {
+            if let Ok(window) = &mut windows.get_single_mut() {
+                if actions.just_pressed(&FlyAction::Look) {
+                    window.cursor_options.grab_mode = CursorGrabMode::Confined;
+                    window.cursor_options.visible = false;
+                } else if actions.just_released(&FlyAction::Look) {
+                    window.cursor_options.grab_mode = CursorGrabMode::None;
+                    window.cursor_options.visible = true;
+                }
+            }
+        }
+
+        let looking = config.look_button.is_none() || actions.pressed(&FlyAction::Look);
+
+        // x to the right, y up and z backwards, like the camera's axes
+        let stick = actions.axis_pair(&FlyAction::Move);
+        let mut direction = Vec3::new(stick.x, 0., -stick.y);
+        direction.y += actions.clamped_value(&FlyAction::Up);
+        direction.y -= actions.clamped_value(&FlyAction::Down);
+        if looking {
+            direction.x += actions.clamped_value(&FlyAction::Right);
+            direction.x -= actions.clamped_value(&FlyAction::Left);
+            direction.z += actions.clamped_value(&FlyAction::Backward);
+            direction.z -= actions.clamped_value(&FlyAction::Forward);
+
+            let scroll = actions.value(&FlyAction::Speed);
+            if scroll != 0. {
+                let (min, max) = ordered(config.min_speed, config.max_speed);
+                config.speed =
+                    (config.speed * (1. + config.speed_sensitivity).powf(scroll)).clamp(min, max);
+            }
+        }
+
+        let mut yaw_pitch = actions.axis_pair(&FlyAction::LookStick)
+            * config.stick_sensitivity
+            * Vec2::new(-1., 1.)
+            * delta_time_sec;
+        if looking {
+            yaw_pitch -= actions.axis_pair(&FlyAction::LookMouse) * config.sensitivity;
+        }
+
+        if direction == Vec3::ZERO && yaw_pitch == Vec2::ZERO {
+            continue;
+        }
+        let Some(fpv) = rig.try_driver_mut::<Fpv>() else {
+            continue;
+        };
+
+        let mut speed = config.speed;
+        if actions.pressed(&FlyAction::Boost) {
+            speed *= config.boost_mult;
+        }
+        if actions.pressed(&FlyAction::Slow) {
+            speed *= config.slow_mult;
+        }
+        let velocity = fpv.set_position(direction, 1., speed, config.world_vertical);
+        fpv.driver_mut::<Position>()
+            .translate(velocity * delta_time_sec);
+        fpv.driver_mut::<YawPitch>()
+            .rotate_yaw_pitch(yaw_pitch.x, yaw_pitch.y);
+    }
+}
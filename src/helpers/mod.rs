@@ -1,4 +1,7 @@
 pub mod cam_ctrl;
 pub mod cone;
 pub mod cursor_grab;
+#[cfg(feature = "drivers")]
+pub mod fly_ctrl;
+pub mod orbit_ctrl;
 pub mod pos_ctrl;
@@ -0,0 +1,296 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use leafwing_input_manager::prelude::*;
+
+use crate::{ordered, prelude::*, system::RigCameras};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct DollyOrbitCtrlSet;
+
+/// This plugin orbits, pans and zooms rigs with the mouse.
+/// Add a [`DollyOrbitCtrlConfig`] to an entity with a [`Rig`] made of a pivot,
+/// a `YawPitch` and an `Arm`:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_dolly::prelude::*;
+///
+/// #[derive(Component)]
+/// struct MainCamera;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, DollyPlugin, DollyOrbitCtrl))
+///         .add_dolly_component(MainCamera)
+///         .add_systems(Startup, setup)
+///         .run();
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         MainCamera,
+///         Camera3d::default(),
+///         Rig::builder()
+///             .with(Position::new(Vec3::ZERO))
+///             .with(YawPitch::new().yaw_degrees(45.0).pitch_degrees(-30.0))
+///             .with(Smooth::new_position_rotation(0.3, 0.3))
+///             .with(Arm::new(Vec3::Z * 8.0))
+///             .build(),
+///         DollyOrbitCtrlConfig {
+///             zoom_to_cursor: true,
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+/// The mouse buttons of the config are bound when the controller is first seen,
+/// insert an `InputMap<OrbitAction>` alongside it for other bindings.
+pub struct DollyOrbitCtrl;
+impl Plugin for DollyOrbitCtrl {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InputManagerPlugin::<OrbitAction>::default());
+        app.add_systems(
+            Update,
+            (dolly_orbit_ctrl_input_setup, dolly_orbit_ctrl_update)
+                .chain()
+                .in_set(DollyOrbitCtrlSet),
+        );
+    }
+}
+
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+pub enum OrbitAction {
+    /// Orbits with [`OrbitAction::Move`] while held
+    Orbit,
+    /// Pans with the cursor while held
+    Pan,
+    SnapLeft,
+    SnapRight,
+    #[actionlike(DualAxis)]
+    Move,
+    #[actionlike(Axis)]
+    Zoom,
+}
+
+/// Settings of the [`DollyOrbitCtrl`] of a rig
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct DollyOrbitCtrlConfig {
+    pub enabled: bool,
+    pub orbit_button: MouseButton,
+    pub pan_button: MouseButton,
+    /// Degrees of yaw and pitch per unit of mouse motion
+    pub sensitivity: Vec2,
+    /// Zoom per line scrolled, 0.1 gets 10% closer
+    pub zoom_sensitivity: f32,
+    /// Limits of the pitch, this and the other limits can be given in either order
+    pub min_pitch_degrees: f32,
+    pub max_pitch_degrees: f32,
+    /// Limits of the arm length of perspective cameras
+    pub min_distance: f32,
+    pub max_distance: f32,
+    /// Limits of the scale of orthographic cameras
+    pub min_scale: f32,
+    pub max_scale: f32,
+    /// Zoom towards the point under the cursor instead of the pivot
+    pub zoom_to_cursor: bool,
+    /// Yaw of [`OrbitAction::SnapLeft`] and [`OrbitAction::SnapRight`]
+    pub snap_degrees: f32,
+    last_cursor: Option<Vec2>,
+}
+
+impl Default for DollyOrbitCtrlConfig {
+    fn default() -> Self {
+        DollyOrbitCtrlConfig {
+            enabled: true,
+            orbit_button: MouseButton::Left,
+            pan_button: MouseButton::Middle,
+            sensitivity: Vec2::splat(0.2),
+            zoom_sensitivity: 0.1,
+            min_pitch_degrees: -89.,
+            max_pitch_degrees: 89.,
+            min_distance: 1.,
+            max_distance: 100.,
+            min_scale: 0.01,
+            max_scale: 100.,
+            zoom_to_cursor: false,
+            snap_degrees: 90.,
+            last_cursor: None,
+        }
+    }
+}
+
+impl DollyOrbitCtrlConfig {
+    /// The configured buttons, mouse motion, the scroll wheel and Z and X to snap
+    pub fn input_map(&self) -> InputMap<OrbitAction> {
+        InputMap::default()
+            .with(OrbitAction::Orbit, self.orbit_button)
+            .with(OrbitAction::Pan, self.pan_button)
+            .with(OrbitAction::SnapLeft, KeyCode::KeyZ)
+            .with(OrbitAction::SnapRight, KeyCode::KeyX)
+            .with_dual_axis(OrbitAction::Move, MouseMove::default())
+            .with_axis(OrbitAction::Zoom, MouseScrollAxis::Y)
+    }
+}
+
+fn dolly_orbit_ctrl_input_setup(
+    mut commands: Commands,
+    ctrls: Query<(Entity, &DollyOrbitCtrlConfig), Without<ActionState<OrbitAction>>>,
+) {
+    for (entity, config) in &ctrls {
+        commands.entity(entity).insert(InputManagerBundle {
+            input_map: config.input_map(),
+            action_state: ActionState::default(),
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn dolly_orbit_ctrl_update(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut ctrls: Query<(
+        Entity,
+        &mut DollyOrbitCtrlConfig,
+        &ActionState<OrbitAction>,
+        &mut Rig,
+    )>,
+    rig_cameras: RigCameras,
+    mut cameras: Query<(&Camera, &GlobalTransform, Option<&mut Projection>)>,
+) {
+    let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+
+    for (entity, mut config, actions, mut rig) in &mut ctrls {
+        let last_cursor =
+            std::mem::replace(&mut config.bypass_change_detection().last_cursor, cursor);
+        if !config.enabled {
+            continue;
+        }
+
+        let motion = actions.axis_pair(&OrbitAction::Move);
+        let mut yaw = 0.;
+        let mut pitch = 0.;
+        if actions.pressed(&OrbitAction::Orbit) {
+            yaw -= motion.x * config.sensitivity.x;
+            pitch -= motion.y * config.sensitivity.y;
+        }
+        if actions.just_pressed(&OrbitAction::SnapLeft) {
+            yaw -= config.snap_degrees;
+        }
+        if actions.just_pressed(&OrbitAction::SnapRight) {
+            yaw += config.snap_degrees;
+        }
+        if yaw != 0. || pitch != 0. {
+            if let Some(yaw_pitch) = rig.try_driver_mut::<YawPitch>() {
+                yaw_pitch.rotate_yaw_pitch(yaw, pitch);
+                let (min, max) = ordered(config.min_pitch_degrees, config.max_pitch_degrees);
+                yaw_pitch.pitch_degrees = yaw_pitch.pitch_degrees.clamp(min, max);
+            }
+        }
+
+        let pan = actions.pressed(&OrbitAction::Pan);
+        let scroll = actions.value(&OrbitAction::Zoom);
+        if !pan && scroll == 0. {
+            continue;
+        }
+
+        // The camera of the rig, the active one for rigs that drive several
+        let Some((camera, camera_transform, mut projection)) = rig_cameras
+            .camera(entity)
+            .and_then(|camera| cameras.get_mut(camera).ok())
+        else {
+            continue;
+        };
+
+        // Points under the cursor are picked on the plane through the pivot facing the camera
+        let forward = camera_transform.forward();
+        let pivot = camera_transform.translation() + forward * arm_distance(&rig).unwrap_or(0.);
+        let viewport_origin = camera
+            .logical_viewport_rect()
+            .map_or(Vec2::ZERO, |rect| rect.min);
+        let on_pivot_plane = |cursor: Option<Vec2>| {
+            let ray = camera
+                .viewport_to_world(camera_transform, cursor? - viewport_origin)
+                .ok()?;
+            let distance = ray.intersect_plane(pivot, InfinitePlane3d::new(forward))?;
+            Some(ray.get_point(distance))
+        };
+
+        if pan {
+            if let Some((from, to)) = on_pivot_plane(last_cursor).zip(on_pivot_plane(cursor)) {
+                if from != to {
+                    move_pivot(&mut rig, from - to);
+                }
+            }
+        }
+
+        if scroll != 0. {
+            let factor = (1. + config.zoom_sensitivity).powf(-scroll);
+            let ratio = match projection.as_deref_mut() {
+                Some(Projection::Orthographic(orthographic)) => {
+                    let scale = match rig.try_lens_driver_mut::<OrthographicZoom>() {
+                        Some(zoom) => &mut zoom.scale,
+                        None => &mut orthographic.scale,
+                    };
+                    zoom_clamped(scale, factor, config.min_scale, config.max_scale)
+                }
+                _ => arm_offset_mut(&mut rig).map_or(1., |offset| {
+                    let mut length = offset.length();
+                    let ratio = zoom_clamped(
+                        &mut length,
+                        factor,
+                        config.min_distance,
+                        config.max_distance,
+                    );
+                    *offset *= ratio;
+                    ratio
+                }),
+            };
+
+            // Moving the pivot along with the zoom keeps the point under the cursor in place
+            if config.zoom_to_cursor && ratio != 1. {
+                if let Some(point) = on_pivot_plane(cursor) {
+                    move_pivot(&mut rig, (point - pivot) * (1. - ratio));
+                }
+            }
+        }
+    }
+}
+
+/// Scales a distance or scale within limits, returns the ratio actually applied
+fn zoom_clamped(value: &mut f32, factor: f32, min: f32, max: f32) -> f32 {
+    let previous = *value;
+    let (min, max) = ordered(min, max);
+    *value = (previous * factor).clamp(min, max);
+    if previous > 0. {
+        *value / previous
+    } else {
+        1.
+    }
+}
+
+fn arm_distance(rig: &Rig) -> Option<f32> {
+    #[cfg(feature = "drivers")]
+    if let Some(arm) = rig.try_driver::<CollisionArm>() {
+        return Some(arm.offset.length());
+    }
+    rig.try_driver::<Arm>().map(|arm| arm.offset.length())
+}
+
+fn arm_offset_mut(rig: &mut Rig) -> Option<&mut Vec3> {
+    #[cfg(feature = "drivers")]
+    if rig.try_driver::<CollisionArm>().is_some() {
+        return rig
+            .try_driver_mut::<CollisionArm>()
+            .map(|arm| &mut arm.offset);
+    }
+    rig.try_driver_mut::<Arm>().map(|arm| &mut arm.offset)
+}
+
+/// Moves what the rig orbits around, the offset of a `FollowEntity` or else its `Position`
+fn move_pivot(rig: &mut Rig, delta: Vec3) {
+    #[cfg(feature = "drivers")]
+    if let Some(follow) = rig.try_driver_mut::<FollowEntity>() {
+        follow.offset += delta;
+        return;
+    }
+    if let Some(position) = rig.try_driver_mut::<Position>() {
+        position.position += delta;
+    }
+}
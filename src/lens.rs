@@ -0,0 +1,257 @@
+use std::{any::Any, fmt::Debug};
+
+use bevy::prelude::*;
+
+use crate::rig_2d::smoothing_t;
+
+/// Projection parameters output by the lens drivers of a [`Rig`](crate::dolly_type::Rig),
+/// written to the camera's [`Projection`] by the [`Dolly`](crate::system::Dolly) systems.
+///
+/// Lens drivers are added with [`RigBuilder::with_lens`](crate::dolly_type::RigBuilder::with_lens)
+/// and run after the transform drivers: [`FovZoom`], [`OrthographicZoom`], [`ClipPlanes`],
+/// [`DollyZoom`] and [`SmoothLens`]. Parameters left at `None` leave the camera's value alone,
+/// so cameras whose rig has no lens drivers keep their projection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Reflect)]
+pub struct Lens {
+    /// Vertical field of view of perspective cameras, in radians
+    pub fov: Option<f32>,
+    /// Scale of orthographic cameras
+    pub orthographic_scale: Option<f32>,
+    pub near: Option<f32>,
+    pub far: Option<f32>,
+}
+
+impl Lens {
+    /// Whether no parameter is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Writes the parameters that apply to the kind of projection
+    pub fn apply_to(&self, projection: &mut Projection) {
+        match projection {
+            Projection::Perspective(perspective) => {
+                if let Some(fov) = self.fov {
+                    perspective.fov = fov;
+                }
+                if let Some(near) = self.near {
+                    perspective.near = near;
+                }
+                if let Some(far) = self.far {
+                    perspective.far = far;
+                }
+            }
+            Projection::Orthographic(orthographic) => {
+                if let Some(scale) = self.orthographic_scale {
+                    orthographic.scale = scale;
+                }
+                if let Some(near) = self.near {
+                    orthographic.near = near;
+                }
+                if let Some(far) = self.far {
+                    orthographic.far = far;
+                }
+            }
+        }
+    }
+}
+
+/// What a [`LensDriver`] gets to work with
+#[derive(Debug, Clone, Copy)]
+pub struct LensUpdateParams<'a> {
+    /// Output of the previous lens driver
+    pub parent: &'a Lens,
+    /// Final transform of the rig
+    pub transform: &'a Transform,
+    pub delta_time_seconds: f32,
+}
+
+/// A driver of the projection of a rig, evaluated after its transform drivers
+pub trait LensDriver: Any + Debug + Send + Sync {
+    fn update(&mut self, params: LensUpdateParams) -> Lens;
+}
+
+/// Downcasting support, implemented for every [`LensDriver`]
+pub trait LensDriverTraits: LensDriver {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: LensDriver> LensDriverTraits for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Sets the vertical field of view of perspective cameras
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FovZoom {
+    pub fov_degrees: f32,
+    /// Limits of [`FovZoom::zoom_by`]
+    pub min_degrees: f32,
+    pub max_degrees: f32,
+}
+
+impl FovZoom {
+    pub fn new(fov_degrees: f32) -> Self {
+        Self {
+            fov_degrees,
+            min_degrees: 1.,
+            max_degrees: 170.,
+        }
+    }
+
+    pub fn with_limits(mut self, min_degrees: f32, max_degrees: f32) -> Self {
+        self.min_degrees = min_degrees;
+        self.max_degrees = max_degrees;
+        self
+    }
+
+    /// Zooms like a lens would, 2.0 shows everything twice as big
+    pub fn zoom_by(&mut self, factor: f32) {
+        let half = (self.fov_degrees.to_radians() * 0.5).tan() / factor.max(f32::EPSILON);
+        self.fov_degrees =
+            (2. * half.atan().to_degrees()).clamp(self.min_degrees, self.max_degrees);
+    }
+}
+
+impl LensDriver for FovZoom {
+    fn update(&mut self, params: LensUpdateParams) -> Lens {
+        Lens {
+            fov: Some(self.fov_degrees.to_radians()),
+            ..*params.parent
+        }
+    }
+}
+
+/// Sets the scale of orthographic cameras
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrthographicZoom {
+    pub scale: f32,
+}
+
+impl OrthographicZoom {
+    pub fn new(scale: f32) -> Self {
+        Self { scale }
+    }
+
+    /// 2.0 shows everything twice as big
+    pub fn zoom_by(&mut self, factor: f32) {
+        self.scale /= factor.max(f32::EPSILON);
+    }
+}
+
+impl LensDriver for OrthographicZoom {
+    fn update(&mut self, params: LensUpdateParams) -> Lens {
+        Lens {
+            orthographic_scale: Some(self.scale),
+            ..*params.parent
+        }
+    }
+}
+
+/// Sets the near and far clipping planes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClipPlanes {
+    pub near: f32,
+    pub far: f32,
+}
+
+impl ClipPlanes {
+    pub fn new(near: f32, far: f32) -> Self {
+        Self { near, far }
+    }
+}
+
+impl LensDriver for ClipPlanes {
+    fn update(&mut self, params: LensUpdateParams) -> Lens {
+        Lens {
+            near: Some(self.near),
+            far: Some(self.far),
+            ..*params.parent
+        }
+    }
+}
+
+/// The vertigo effect: adjusts the field of view so the subject keeps its size on screen
+/// while the camera moves towards or away from it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DollyZoom {
+    /// Position of the subject
+    pub target: Vec3,
+    /// Height of the view at the subject's distance, in world units
+    pub height: f32,
+}
+
+impl DollyZoom {
+    pub fn new(target: Vec3, height: f32) -> Self {
+        Self { target, height }
+    }
+
+    /// Keeps the framing of a field of view at a distance from the subject
+    pub fn from_fov_degrees(target: Vec3, fov_degrees: f32, distance: f32) -> Self {
+        Self::new(
+            target,
+            2. * distance * (fov_degrees.to_radians() * 0.5).tan(),
+        )
+    }
+}
+
+impl LensDriver for DollyZoom {
+    fn update(&mut self, params: LensUpdateParams) -> Lens {
+        let distance = params.transform.translation.distance(self.target);
+        Lens {
+            fov: Some(2. * (self.height * 0.5).atan2(distance)),
+            ..*params.parent
+        }
+    }
+}
+
+/// Exponentially smooths the output of the previous lens drivers,
+/// with the same smoothness scale as dolly's `Smooth`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SmoothLens {
+    pub smoothness: f32,
+    smoothed: Option<Lens>,
+}
+
+impl SmoothLens {
+    pub fn new(smoothness: f32) -> Self {
+        Self {
+            smoothness,
+            smoothed: None,
+        }
+    }
+}
+
+impl LensDriver for SmoothLens {
+    fn update(&mut self, params: LensUpdateParams) -> Lens {
+        let t = smoothing_t(self.smoothness, params.delta_time_seconds);
+        let target = *params.parent;
+        let smooth = |prev: Option<f32>, target: Option<f32>| match (prev, target) {
+            (Some(prev), Some(target)) => Some(prev.lerp(target, t)),
+            (_, target) => target,
+        };
+
+        let smoothed = match self.smoothed {
+            Some(prev) => Lens {
+                fov: smooth(prev.fov, target.fov),
+                // Smoothed in log space, so zooming in and out feel the same
+                orthographic_scale: smooth(
+                    prev.orthographic_scale.map(|s| s.max(f32::EPSILON).ln()),
+                    target.orthographic_scale.map(|s| s.max(f32::EPSILON).ln()),
+                )
+                .map(f32::exp),
+                near: smooth(prev.near, target.near),
+                far: smooth(prev.far, target.far),
+            },
+            None => target,
+        };
+        self.smoothed = Some(smoothed);
+        smoothed
+    }
+}
@@ -1,20 +1,48 @@
 pub use dolly;
 
+pub mod blend;
+pub mod brain;
 pub mod dolly_type;
+pub mod driver_state;
 #[cfg(feature = "drivers")]
 pub mod drivers;
 #[cfg(feature = "helpers")]
 pub mod helpers;
+pub mod lens;
+pub mod rig_2d;
+#[cfg(feature = "asset")]
+pub mod rig_asset;
+pub mod sequence;
 pub mod system;
+pub mod testing;
+pub mod timestep;
+
+/// Orders a pair of limits. Limits are public fields, so they are ordered where they are used
+/// rather than by their builders.
+#[cfg(any(feature = "drivers", feature = "helpers"))]
+pub(crate) fn ordered(a: f32, b: f32) -> (f32, f32) {
+    (a.min(b), a.max(b))
+}
 
 pub mod prelude {
-    pub use crate::{dolly::prelude::*, dolly_type::*, system::*};
+    pub use crate::{
+        blend::*, brain::*, dolly::prelude::*, dolly_type::*, driver_state::*, lens::*, rig_2d::*,
+        sequence::*, system::*, timestep::*,
+    };
 
     #[cfg(feature = "drivers")]
-    pub use crate::drivers::{follow::*, fpv::*};
+    pub use crate::drivers::{
+        collision::*, confiner::*, entity::*, follow::*, fpv::*, framing::*, limits::*,
+        look_ahead::*, occlusion::*, platform_snap::*, shake::*, target_group::*, track::*,
+        view::*,
+    };
+    #[cfg(all(feature = "helpers", feature = "drivers"))]
+    pub use crate::helpers::fly_ctrl::*;
     #[cfg(feature = "helpers")]
     pub use crate::helpers::{
         *,
-        {cone::*, cursor_grab::*, pos_ctrl::*},
+        {cone::*, cursor_grab::*, orbit_ctrl::*, pos_ctrl::*},
     };
+    #[cfg(feature = "asset")]
+    pub use crate::rig_asset::*;
 }
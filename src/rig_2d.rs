@@ -0,0 +1,400 @@
+use std::{any::Any, f32::consts::PI, fmt::Debug};
+
+use bevy::prelude::*;
+
+use crate::timestep::Substeps;
+
+/// Output of a [`Rig2d`], written to the camera by the 2D [`Dolly`](crate::system::Dolly) systems.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct Transform2d {
+    pub position: Vec2,
+    /// Rotation around Z in radians
+    pub roll: f32,
+    /// Zoom factor, the camera's orthographic scale is set to `1.0 / zoom`
+    pub zoom: f32,
+}
+
+impl Transform2d {
+    pub const IDENTITY: Self = Self {
+        position: Vec2::ZERO,
+        roll: 0.,
+        zoom: 1.,
+    };
+
+    /// Writes the position and roll to a camera transform, its Z translation is kept
+    pub fn apply_to(&self, transform: &mut Transform) {
+        transform.translation = self.position.extend(transform.translation.z);
+        transform.rotation = Quat::from_rotation_z(self.roll);
+    }
+
+    /// Orthographic projection scale for this zoom
+    pub fn scale(&self) -> f32 {
+        1. / self.zoom.max(f32::EPSILON)
+    }
+}
+
+/// Rounds the output of a [`Rig2d`] to whole screen pixels, so pixel-art sprites don't shimmer.
+///
+/// The drivers keep their unsnapped state, so smoothing carries on smoothly between pixels.
+/// The grid is one screen pixel at the output's orthographic scale. Meant for rigs without roll.
+/// ```rust
+/// # use bevy_dolly::prelude::*;
+/// # let rig =
+/// Rig2d::builder()
+///     .with(Position2d::default())
+///     .with(Zoom::new(3.))
+///     .with(Smooth2d::new_position(0.5))
+///     .with_pixel_snap(PixelSnap::new().with_integer_zoom(true))
+///     .build()
+/// # ;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct PixelSnap {
+    /// World units per screen pixel at an orthographic scale of 1, 1.0 for the default
+    /// `ScalingMode::WindowSize`
+    pub units_per_pixel: f32,
+    /// Rounds the zoom to a whole number, or its inverse to one when zoomed out,
+    /// so every texel covers the same number of pixels
+    pub integer_zoom: bool,
+}
+
+impl Default for PixelSnap {
+    fn default() -> Self {
+        Self {
+            units_per_pixel: 1.,
+            integer_zoom: false,
+        }
+    }
+}
+
+impl PixelSnap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_units_per_pixel(mut self, units_per_pixel: f32) -> Self {
+        self.units_per_pixel = units_per_pixel;
+        self
+    }
+
+    pub fn with_integer_zoom(mut self, integer_zoom: bool) -> Self {
+        self.integer_zoom = integer_zoom;
+        self
+    }
+
+    /// Snaps a transform to the pixel grid
+    pub fn apply(&self, transform: &Transform2d) -> Transform2d {
+        let zoom = transform.zoom.max(f32::EPSILON);
+        let zoom = match self.integer_zoom {
+            true if zoom >= 1. => zoom.round(),
+            true => 1. / (1. / zoom).round(),
+            false => zoom,
+        };
+        let grid = self.units_per_pixel / zoom;
+        Transform2d {
+            position: if grid > 0. {
+                (transform.position / grid).round() * grid
+            } else {
+                transform.position
+            },
+            zoom,
+            ..*transform
+        }
+    }
+}
+
+impl Default for Transform2d {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A driver of a [`Rig2d`], the 2D counterpart of dolly's `RigDriver`.
+pub trait RigDriver2d: Any + Debug + Send + Sync {
+    /// Takes the output of the previous driver and returns this driver's output
+    fn update(&mut self, parent: &Transform2d, delta_time_seconds: f32) -> Transform2d;
+}
+
+/// Downcasting support, implemented for every [`RigDriver2d`]
+pub trait RigDriver2dTraits: RigDriver2d {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: RigDriver2d> RigDriver2dTraits for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// A 2D camera rig, for cameras with an [`OrthographicProjection`].
+///
+/// Works like [`Rig`](crate::dolly_type::Rig) with 2D drivers instead: position in the XY plane,
+/// roll around Z and zoom, where a zoom of 1.0 is an orthographic scale of 1.0.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// # fn setup(mut commands: Commands) {
+/// commands.spawn((
+///     MainCamera,
+///     Camera2d,
+///     Rig2d::builder()
+///         .with(Position2d::default())
+///         .with(Zoom::new(2.))
+///         .with(Smooth2d::new_position(0.25))
+///         .build(),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug)]
+pub struct Rig2d {
+    pub drivers: Vec<Box<dyn RigDriver2dTraits>>,
+    pub final_transform: Transform2d,
+    /// Splits long frames into several updates, see [`Rig2dBuilder::with_substeps`]
+    pub substeps: Option<Substeps>,
+    /// Snaps the output to pixels, [`Rig2d::final_transform`] stays unsnapped
+    pub pixel_snap: Option<PixelSnap>,
+}
+
+impl Rig2d {
+    pub fn builder() -> Rig2dBuilder {
+        Rig2dBuilder {
+            drivers: Vec::new(),
+            substeps: None,
+            pixel_snap: None,
+        }
+    }
+
+    /// Runs the drivers in order and returns the final transform, snapped with the [`PixelSnap`]
+    pub fn update(&mut self, delta_time_seconds: f32) -> Transform2d {
+        let (steps, step) = self.substeps.map_or((1, delta_time_seconds), |substeps| {
+            substeps.split(delta_time_seconds)
+        });
+        for _ in 0..steps {
+            self.final_transform = self
+                .drivers
+                .iter_mut()
+                .fold(Transform2d::IDENTITY, |parent, driver| {
+                    driver.update(&parent, step)
+                });
+        }
+        self.output()
+    }
+
+    /// The final transform, snapped with the [`PixelSnap`] if there is one
+    pub fn output(&self) -> Transform2d {
+        self.pixel_snap.map_or(self.final_transform, |snap| {
+            snap.apply(&self.final_transform)
+        })
+    }
+
+    pub fn driver<T: RigDriver2d>(&self) -> &T {
+        self.try_driver::<T>()
+            .unwrap_or_else(|| panic!("No {} driver in rig", std::any::type_name::<T>()))
+    }
+
+    pub fn driver_mut<T: RigDriver2d>(&mut self) -> &mut T {
+        self.try_driver_mut::<T>()
+            .unwrap_or_else(|| panic!("No {} driver in rig", std::any::type_name::<T>()))
+    }
+
+    pub fn try_driver<T: RigDriver2d>(&self) -> Option<&T> {
+        self.drivers
+            .iter()
+            .find_map(|driver| driver.as_any().downcast_ref::<T>())
+    }
+
+    pub fn try_driver_mut<T: RigDriver2d>(&mut self) -> Option<&mut T> {
+        self.drivers
+            .iter_mut()
+            .find_map(|driver| driver.as_any_mut().downcast_mut::<T>())
+    }
+}
+
+pub struct Rig2dBuilder {
+    drivers: Vec<Box<dyn RigDriver2dTraits>>,
+    substeps: Option<Substeps>,
+    pixel_snap: Option<PixelSnap>,
+}
+
+impl Rig2dBuilder {
+    pub fn with(mut self, driver: impl RigDriver2d) -> Self {
+        self.drivers.push(Box::new(driver));
+        self
+    }
+
+    /// Updates the rig in steps of at most `max_step_seconds`, up to `max_substeps` per frame
+    pub fn with_substeps(mut self, max_step_seconds: f32, max_substeps: u32) -> Self {
+        self.substeps = Some(Substeps::new(max_step_seconds, max_substeps));
+        self
+    }
+
+    /// Rounds the output to whole pixels, see [`PixelSnap`]
+    pub fn with_pixel_snap(mut self, pixel_snap: PixelSnap) -> Self {
+        self.pixel_snap = Some(pixel_snap);
+        self
+    }
+
+    pub fn build(self) -> Rig2d {
+        let mut rig = Rig2d {
+            drivers: self.drivers,
+            final_transform: Transform2d::IDENTITY,
+            substeps: self.substeps,
+            pixel_snap: self.pixel_snap,
+        };
+        rig.update(0.);
+        rig
+    }
+}
+
+/// Sets the position of the rig
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Position2d {
+    pub position: Vec2,
+}
+
+impl Position2d {
+    pub fn new(position: Vec2) -> Self {
+        Self { position }
+    }
+
+    pub fn translate(&mut self, delta: Vec2) {
+        self.position += delta;
+    }
+}
+
+impl RigDriver2d for Position2d {
+    fn update(&mut self, parent: &Transform2d, _: f32) -> Transform2d {
+        Transform2d {
+            position: self.position,
+            ..*parent
+        }
+    }
+}
+
+/// Sets the rotation of the rig around Z, in radians
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Roll {
+    pub roll: f32,
+}
+
+impl Roll {
+    pub fn new(roll: f32) -> Self {
+        Self { roll }
+    }
+
+    pub fn rotate(&mut self, radians: f32) {
+        self.roll = (self.roll + radians).rem_euclid(2. * PI);
+    }
+}
+
+impl RigDriver2d for Roll {
+    fn update(&mut self, parent: &Transform2d, _: f32) -> Transform2d {
+        Transform2d {
+            roll: self.roll,
+            ..*parent
+        }
+    }
+}
+
+/// Sets the zoom of the rig, 2.0 shows everything twice as big
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Zoom {
+    pub zoom: f32,
+}
+
+impl Default for Zoom {
+    fn default() -> Self {
+        Self { zoom: 1. }
+    }
+}
+
+impl Zoom {
+    pub fn new(zoom: f32) -> Self {
+        Self { zoom }
+    }
+
+    /// Multiplies the zoom, so repeated steps feel the same at any zoom level
+    pub fn zoom_by(&mut self, factor: f32) {
+        self.zoom *= factor;
+    }
+}
+
+impl RigDriver2d for Zoom {
+    fn update(&mut self, parent: &Transform2d, _: f32) -> Transform2d {
+        Transform2d {
+            zoom: self.zoom,
+            ..*parent
+        }
+    }
+}
+
+/// Exponentially smooths the output of the previous drivers,
+/// with the same smoothness scale as dolly's `Smooth`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Smooth2d {
+    pub position_smoothness: f32,
+    pub roll_smoothness: f32,
+    pub zoom_smoothness: f32,
+    smoothed: Option<Transform2d>,
+}
+
+impl Smooth2d {
+    pub fn new(position_smoothness: f32, roll_smoothness: f32, zoom_smoothness: f32) -> Self {
+        Self {
+            position_smoothness,
+            roll_smoothness,
+            zoom_smoothness,
+            smoothed: None,
+        }
+    }
+
+    /// Only smooth position
+    pub fn new_position(position_smoothness: f32) -> Self {
+        Self::new(position_smoothness, 0., 0.)
+    }
+}
+
+/// Fraction of the way to the target that exponential smoothing covers in a frame.
+///
+/// Uses the same rate as dolly's `Smooth`, so a smoothness means the same in every driver.
+/// A smoothness of 0 or less goes all the way.
+pub(crate) fn smoothing_t(smoothness: f32, delta_time_seconds: f32) -> f32 {
+    const SMOOTHNESS_MULT: f32 = 8.0;
+
+    if smoothness <= 0. {
+        return 1.;
+    }
+    1.0 - (-SMOOTHNESS_MULT * delta_time_seconds / smoothness).exp()
+}
+
+impl RigDriver2d for Smooth2d {
+    fn update(&mut self, parent: &Transform2d, delta_time_seconds: f32) -> Transform2d {
+        let prev = self.smoothed.unwrap_or(*parent);
+        let t = |smoothness| smoothing_t(smoothness, delta_time_seconds);
+
+        // Roll takes the short way around
+        let roll_delta = (parent.roll - prev.roll + PI).rem_euclid(2. * PI) - PI;
+        let smoothed = Transform2d {
+            position: prev
+                .position
+                .lerp(parent.position, t(self.position_smoothness)),
+            roll: prev.roll + roll_delta * t(self.roll_smoothness),
+            // Zoom is smoothed in log space, so zooming in and out feel the same
+            zoom: prev
+                .zoom
+                .max(f32::EPSILON)
+                .ln()
+                .lerp(parent.zoom.max(f32::EPSILON).ln(), t(self.zoom_smoothness))
+                .exp(),
+        };
+        self.smoothed = Some(smoothed);
+        smoothed
+    }
+}
@@ -0,0 +1,221 @@
+use std::fmt;
+
+use crate::prelude::*;
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// A rig authored in data instead of code, loaded from `*.rig.ron` or `*.rig.json` files:
+/// ```ron
+/// (
+///     drivers: [
+///         Position(position: (0.0, 0.0, 0.0)),
+///         YawPitch(yaw_degrees: 45.0, pitch_degrees: -30.0),
+///         Smooth(position_smoothness: 0.3, rotation_smoothness: 0.3),
+///         Arm(offset: (0.0, 0.0, 4.0)),
+///     ],
+/// )
+/// ```
+#[derive(Asset, TypePath, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RigDescription {
+    /// The drivers of the rig, in the order they are evaluated
+    pub drivers: Vec<DriverState>,
+}
+
+impl RigDescription {
+    pub fn build(&self) -> Rig {
+        self.drivers
+            .iter()
+            .fold(Rig::builder(), |builder, state| builder.with_state(state))
+            .build()
+    }
+}
+
+/// Builds a [`Rig`] on its entity once the [`RigDescription`] has loaded,
+/// and rebuilds it whenever the asset is hot-reloaded.
+///
+/// On a rebuild, the position and rotation inputs of the current rig
+/// ([`Position`], [`Rotation`] and [`YawPitch`]) are carried over,
+/// so the camera stays where gameplay put it.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Deref, DerefMut)]
+pub struct RigHandle(pub Handle<RigDescription>);
+
+#[derive(Default)]
+pub struct RigDescriptionLoader;
+
+impl AssetLoader for RigDescriptionLoader {
+    type Asset = RigDescription;
+    type Settings = ();
+    type Error = RonJsonLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        load_ron_or_json(reader, load_context).await
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rig.ron", "rig.json"]
+    }
+}
+
+/// Error of the loaders of assets written in RON or JSON, ie. [`RigDescriptionLoader`]
+#[derive(Debug)]
+pub enum RonJsonLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for RonJsonLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RonJsonLoaderError::Io(e) => write!(f, "Could not read asset: {e}"),
+            RonJsonLoaderError::Ron(e) => write!(f, "Could not parse RON asset: {e}"),
+            RonJsonLoaderError::Json(e) => write!(f, "Could not parse JSON asset: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for RonJsonLoaderError {}
+
+impl From<std::io::Error> for RonJsonLoaderError {
+    fn from(e: std::io::Error) -> Self {
+        RonJsonLoaderError::Io(e)
+    }
+}
+
+/// Reads an asset from RON, or from JSON if its path ends in `.json`
+pub(crate) async fn load_ron_or_json<T: DeserializeOwned>(
+    reader: &mut dyn Reader,
+    load_context: &LoadContext<'_>,
+) -> Result<T, RonJsonLoaderError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).await?;
+
+    if load_context.path().to_string_lossy().ends_with(".json") {
+        serde_json::from_slice(&bytes).map_err(RonJsonLoaderError::Json)
+    } else {
+        ron::de::from_bytes(&bytes).map_err(RonJsonLoaderError::Ron)
+    }
+}
+
+/// Position and rotation inputs of a rig, carried over when its description is reloaded
+#[derive(Default)]
+struct Pose {
+    position: Option<Vec3>,
+    rotation: Option<Quat>,
+    yaw_pitch: Option<(f32, f32)>,
+}
+
+impl Pose {
+    /// Reads the first inputs of each kind, including those nested in other drivers
+    fn read(&mut self, states: &[DriverState]) {
+        for state in states {
+            match state {
+                DriverState::Position { position } => {
+                    self.position.get_or_insert(*position);
+                }
+                DriverState::Rotation { rotation } => {
+                    self.rotation.get_or_insert(*rotation);
+                }
+                DriverState::YawPitch {
+                    yaw_degrees,
+                    pitch_degrees,
+                } => {
+                    self.yaw_pitch.get_or_insert((*yaw_degrees, *pitch_degrees));
+                }
+                #[cfg(feature = "drivers")]
+                DriverState::Fpv { drivers } | DriverState::MovableLookAt { drivers } => {
+                    self.read(drivers);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Writes each input to the first state of its kind, in the same order they were read
+    fn write(&mut self, states: &mut [DriverState]) {
+        for state in states {
+            match state {
+                DriverState::Position { position } => {
+                    if let Some(old) = self.position.take() {
+                        *position = old;
+                    }
+                }
+                DriverState::Rotation { rotation } => {
+                    if let Some(old) = self.rotation.take() {
+                        *rotation = old;
+                    }
+                }
+                DriverState::YawPitch {
+                    yaw_degrees,
+                    pitch_degrees,
+                } => {
+                    if let Some((yaw, pitch)) = self.yaw_pitch.take() {
+                        *yaw_degrees = yaw;
+                        *pitch_degrees = pitch;
+                    }
+                }
+                #[cfg(feature = "drivers")]
+                DriverState::Fpv { drivers } | DriverState::MovableLookAt { drivers } => {
+                    self.write(drivers);
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Replaces the first position and rotation inputs of a description with those of the current rig
+fn carry_over_pose(rig: &Rig, states: &mut [DriverState]) {
+    let mut pose = Pose::default();
+    pose.read(&DriverState::capture_all(&rig.drivers));
+    pose.write(states);
+}
+
+pub(crate) fn build_described_rigs(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<RigDescription>>,
+    descriptions: Res<Assets<RigDescription>>,
+    mut rigs: Query<(Entity, Ref<RigHandle>, Option<&mut Rig>)>,
+) {
+    let reloaded = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    for (entity, handle, rig) in &mut rigs {
+        if !handle.is_changed() && !reloaded.contains(&handle.id()) {
+            continue;
+        }
+        let Some(description) = descriptions.get(&handle.0) else {
+            continue;
+        };
+
+        match rig {
+            Some(mut rig) => {
+                // Built from the carried over inputs, so smoothing starts out at the current pose
+                let mut description = description.clone();
+                carry_over_pose(&rig, &mut description.drivers);
+                let lens_drivers = std::mem::take(&mut rig.lens_drivers);
+                let (final_lens, substeps) = (rig.final_lens, rig.substeps);
+                *rig = description.build();
+                rig.lens_drivers = lens_drivers;
+                rig.final_lens = final_lens;
+                rig.substeps = substeps;
+            }
+            None => {
+                commands.entity(entity).insert(description.build());
+            }
+        }
+    }
+}
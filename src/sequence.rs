@@ -0,0 +1,442 @@
+use crate::{
+    prelude::*,
+    system::{entity_transform, EntityTransforms, RigCameras},
+};
+use bevy::{
+    math::curve::{Curve, EaseFunction, EasingCurve},
+    prelude::*,
+    utils::HashMap,
+};
+#[cfg(feature = "asset")]
+use {
+    crate::rig_asset::load_ron_or_json,
+    bevy::asset::{io::Reader, AssetLoader, LoadContext},
+    serde::{Deserialize, Serialize},
+};
+
+/// A pose of a [`CameraSequence`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "asset", derive(Serialize, Deserialize))]
+pub struct CameraKeyframe {
+    /// Seconds from the start of the sequence
+    pub time: f32,
+    pub position: Vec3,
+    #[cfg_attr(feature = "asset", serde(default))]
+    pub rotation: Quat,
+    /// Vertical field of view in degrees, the camera's is left alone when no keyframe has one
+    #[cfg_attr(feature = "asset", serde(default))]
+    pub fov_degrees: Option<f32>,
+    /// [`Name`] of an entity to look at, overrides the rotation
+    #[cfg_attr(feature = "asset", serde(default))]
+    pub look_at: Option<String>,
+    /// Easing of the segment from this keyframe to the next
+    #[cfg_attr(feature = "asset", serde(default = "linear"))]
+    pub ease: EaseFunction,
+}
+
+#[cfg(feature = "asset")]
+fn linear() -> EaseFunction {
+    EaseFunction::Linear
+}
+
+impl CameraKeyframe {
+    pub fn new(time: f32, position: Vec3) -> Self {
+        Self {
+            time,
+            position,
+            rotation: Quat::IDENTITY,
+            fov_degrees: None,
+            look_at: None,
+            ease: EaseFunction::Linear,
+        }
+    }
+
+    pub fn with_rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    pub fn with_fov_degrees(mut self, fov_degrees: f32) -> Self {
+        self.fov_degrees = Some(fov_degrees);
+        self
+    }
+
+    pub fn looking_at(mut self, name: impl Into<String>) -> Self {
+        self.look_at = Some(name.into());
+        self
+    }
+
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+}
+
+/// A named point in time of a [`CameraSequence`], reported by [`SequenceMarkerReached`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "asset", derive(Serialize, Deserialize))]
+pub struct SequenceMarker {
+    pub time: f32,
+    pub name: String,
+}
+
+/// Where a [`CameraSequence`] puts the camera at a point in time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SequencePose {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub fov_degrees: Option<f32>,
+}
+
+/// A keyframed camera path for cutscenes, played by a [`SequencePlayer`].
+///
+/// Built in code or loaded from `*.sequence.ron` or `*.sequence.json` files with the `asset` feature:
+/// ```ron
+/// (
+///     keyframes: [
+///         (time: 0.0, position: (-6.0, 2.0, 6.0), look_at: Some("Sheep")),
+///         (time: 3.0, position: (6.0, 3.0, 6.0), look_at: Some("Sheep"), fov_degrees: Some(30.0), ease: CubicInOut),
+///         (time: 5.0, position: (0.0, 8.0, 0.5), rotation: (-0.7071068, 0.0, 0.0, 0.7071068)),
+///     ],
+///     markers: [(time: 3.0, name: "close_up")],
+/// )
+/// ```
+#[derive(Asset, TypePath, Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "asset", derive(Serialize, Deserialize))]
+pub struct CameraSequence {
+    /// Poses sorted by time
+    pub keyframes: Vec<CameraKeyframe>,
+    #[cfg_attr(feature = "asset", serde(default))]
+    pub markers: Vec<SequenceMarker>,
+}
+
+impl CameraSequence {
+    pub fn new(keyframes: Vec<CameraKeyframe>) -> Self {
+        Self {
+            keyframes,
+            markers: Vec::new(),
+        }
+    }
+
+    pub fn with_marker(mut self, time: f32, name: impl Into<String>) -> Self {
+        self.markers.push(SequenceMarker {
+            time,
+            name: name.into(),
+        });
+        self
+    }
+
+    /// Time of the last keyframe
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0., |k| k.time)
+    }
+
+    /// The pose at `time`, looking up the position of look-at targets by name with `find`
+    pub fn sample(&self, time: f32, find: impl Fn(&str) -> Option<Vec3>) -> Option<SequencePose> {
+        let last = self.keyframes.len().checked_sub(1)?;
+        let to_index = self.keyframes.partition_point(|k| k.time <= time).min(last);
+        let from_index = to_index.saturating_sub(1);
+        let (from, to) = (&self.keyframes[from_index], &self.keyframes[to_index]);
+
+        let span = to.time - from.time;
+        let t = if span > 0. {
+            EasingCurve::new(0., 1., from.ease).sample_clamped((time - from.time) / span)
+        } else {
+            0.
+        };
+        let position = from.position.lerp(to.position, t);
+
+        // Look-at keyframes look from the current position, so a target is tracked all segment long
+        let rotation = |keyframe: &CameraKeyframe| {
+            keyframe
+                .look_at
+                .as_deref()
+                .and_then(&find)
+                .filter(|target| *target != position)
+                .map_or(keyframe.rotation, |target| {
+                    Transform::from_translation(position)
+                        .looking_at(target, Vec3::Y)
+                        .rotation
+                })
+        };
+
+        // Keyframes without a field of view hold the last one set
+        let fov = |index: usize| {
+            self.keyframes[..=index]
+                .iter()
+                .rev()
+                .find_map(|k| k.fov_degrees)
+        };
+        let fov_degrees = match (fov(from_index), fov(to_index)) {
+            (Some(a), Some(b)) => Some(a.lerp(b, t)),
+            (a, b) => a.or(b),
+        };
+
+        Some(SequencePose {
+            position,
+            rotation: rotation(from).slerp(rotation(to), t),
+            fov_degrees,
+        })
+    }
+}
+
+/// Sent when a [`SequencePlayer`] plays past a [`SequenceMarker`]
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct SequenceMarkerReached {
+    pub player: Entity,
+    pub name: String,
+}
+
+/// Sent when a [`SequencePlayer`] that doesn't loop reaches the end of its sequence
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceFinished {
+    pub player: Entity,
+}
+
+/// Plays a [`CameraSequence`] on the [`Rig`] of its entity.
+///
+/// Every frame the pose of the sequence is written to the [`Position`] and [`Rotation`] drivers
+/// of the rig, so the rest of the rig, ie. `Smooth` or `Shake`, still applies. The field of view
+/// is written to the rig's [`FovZoom`] lens driver, or straight to the perspective projection of
+/// the camera the rig drives if it has none. Players can be paused, seeked and looped, and send
+/// [`SequenceMarkerReached`] at the markers of the sequence.
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_dolly::prelude::*;
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// # fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// commands.spawn((
+///     MainCamera,
+///     Camera3d::default(),
+///     Rig::builder()
+///         .with(Position::default())
+///         .with(Rotation::default())
+///         .with(Smooth::new_position_rotation(0.2, 0.2))
+///         .build(),
+///     SequencePlayer::new(asset_server.load("intro.sequence.ron")),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct SequencePlayer {
+    pub sequence: Handle<CameraSequence>,
+    /// Playback rate, 1 is real time
+    pub speed: f32,
+    /// Start over at the end instead of stopping
+    pub looping: bool,
+    time: f32,
+    playing: bool,
+    seeked: bool,
+    /// Markers right at `time` are still to be reported, at the start of playback or after a seek
+    at_unreported_time: bool,
+}
+
+impl SequencePlayer {
+    /// A player that starts playing right away
+    pub fn new(sequence: Handle<CameraSequence>) -> Self {
+        Self {
+            sequence,
+            speed: 1.,
+            looping: false,
+            time: 0.,
+            playing: true,
+            seeked: true,
+            at_unreported_time: true,
+        }
+    }
+
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Starts out paused
+    pub fn paused(mut self) -> Self {
+        self.playing = false;
+        self
+    }
+
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Jumps to a point in time without reporting the markers in between
+    pub fn seek(&mut self, time: f32) {
+        self.time = time.max(0.);
+        self.seeked = true;
+        self.at_unreported_time = true;
+    }
+
+    /// Seconds into the sequence
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Advances playback, returns the time played to report markers in
+    fn advance(&mut self, duration: f32, delta_time_seconds: f32) -> Option<PlayedTime> {
+        if !self.playing {
+            return None;
+        }
+        let mut played = PlayedTime {
+            start: self.time,
+            start_inclusive: std::mem::take(&mut self.at_unreported_time),
+            end: self.time + delta_time_seconds * self.speed,
+            wrapped_end: None,
+        };
+        self.time = played.end;
+        if self.time >= duration {
+            if self.looping && duration > 0. {
+                self.time = self.time.rem_euclid(duration);
+                played.wrapped_end = Some(self.time);
+            } else {
+                self.time = duration;
+                self.playing = false;
+            }
+        }
+        Some(played)
+    }
+}
+
+/// Time played by a [`SequencePlayer`] in a frame
+struct PlayedTime {
+    start: f32,
+    /// Markers right at `start` haven't been reported by an earlier frame
+    start_inclusive: bool,
+    end: f32,
+    /// End of the next lap when looping wrapped around, which starts with the markers at 0
+    wrapped_end: Option<f32>,
+}
+
+impl PlayedTime {
+    fn contains(&self, time: f32) -> bool {
+        let after_start = time > self.start || (self.start_inclusive && time == self.start);
+        (after_start && time <= self.end) || self.wrapped_end.is_some_and(|end| time <= end)
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(crate) fn play_sequences(
+    time: Res<Time>,
+    sequences: Option<Res<Assets<CameraSequence>>>,
+    mut players: Query<(Entity, &mut SequencePlayer, &mut Rig)>,
+    rig_cameras: RigCameras,
+    named: Query<(Entity, &Name)>,
+    transforms: EntityTransforms,
+    mut projections: Query<&mut Projection>,
+    mut markers: EventWriter<SequenceMarkerReached>,
+    mut finished: EventWriter<SequenceFinished>,
+) {
+    let Some(sequences) = sequences else {
+        return;
+    };
+    for (entity, mut player, mut rig) in &mut players {
+        let Some(sequence) = sequences.get(&player.sequence) else {
+            continue;
+        };
+        if !player.playing && !player.seeked {
+            continue;
+        }
+        let duration = sequence.duration();
+        let was_playing = player.playing;
+        player.seeked = false;
+
+        if let Some(played) = player.advance(duration, time.delta_secs()) {
+            markers.send_batch(
+                sequence
+                    .markers
+                    .iter()
+                    .filter(|marker| played.contains(marker.time))
+                    .map(|marker| SequenceMarkerReached {
+                        player: entity,
+                        name: marker.name.clone(),
+                    }),
+            );
+        }
+        if was_playing && !player.playing {
+            finished.send(SequenceFinished { player: entity });
+        }
+
+        // Only names the sequence looks at are looked up
+        let targets: HashMap<&str, Vec3> = named
+            .iter()
+            .filter(|(_, name)| {
+                sequence
+                    .keyframes
+                    .iter()
+                    .any(|k| k.look_at.as_deref() == Some(name.as_str()))
+            })
+            .filter_map(|(entity, name)| {
+                let transform = entity_transform(&transforms, entity, false)?;
+                Some((name.as_str(), transform.translation))
+            })
+            .collect();
+        let Some(pose) = sequence.sample(player.time, |name| targets.get(name).copied()) else {
+            continue;
+        };
+
+        if let Some(position) = rig.try_driver::<Position>().map(|d| d.position) {
+            if position != pose.position {
+                rig.driver_mut::<Position>().position = pose.position;
+            }
+        }
+        if let Some(rotation) = rig.try_driver::<Rotation>().map(|d| d.rotation) {
+            if rotation != pose.rotation {
+                rig.driver_mut::<Rotation>().rotation = pose.rotation;
+            }
+        }
+        let Some(fov) = pose.fov_degrees else {
+            continue;
+        };
+        if let Some(zoom) = rig.try_lens_driver::<FovZoom>() {
+            if zoom.fov_degrees != fov {
+                rig.lens_driver_mut::<FovZoom>().fov_degrees = fov;
+            }
+        } else {
+            for camera in rig_cameras.cameras(entity) {
+                if let Ok(mut projection) = projections.get_mut(camera) {
+                    if let Projection::Perspective(perspective) = projection.as_mut() {
+                        perspective.fov = fov.to_radians();
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "asset")]
+#[derive(Default)]
+pub struct CameraSequenceLoader;
+
+#[cfg(feature = "asset")]
+impl AssetLoader for CameraSequenceLoader {
+    type Asset = CameraSequence;
+    type Settings = ();
+    type Error = RonJsonLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        load_ron_or_json(reader, load_context).await
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sequence.ron", "sequence.json"]
+    }
+}
@@ -1,11 +1,200 @@
 use std::marker::PhantomData;
 
 use crate::prelude::*;
-use bevy::prelude::*;
+use bevy::{
+    ecs::{entity::EntityHashSet, system::SystemParam},
+    prelude::*,
+    render::camera::CameraUpdateSystem,
+    transform::TransformSystem,
+};
 
+/// All dolly systems, scheduled in [`PostUpdate`] by [`DollyPlugin`].
+///
+/// Runs before [`TransformSystem::TransformPropagate`] and [`CameraUpdateSystem`], so cameras are
+/// placed from this frame's target transforms instead of lagging a frame behind them.
+/// Input systems in [`Update`] always run earlier; order input that lives in [`PostUpdate`]
+/// with `.before(DollyUpdateSet)`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
 pub struct DollyUpdateSet;
 
+/// First part of [`DollyUpdateSet`], systems that feed the drivers of a rig, ie. copying target
+/// positions into them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct DollyDriveSet;
+
+/// Second part of [`DollyUpdateSet`], the [`Dolly`] systems that evaluate rigs and write their
+/// output to cameras.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct DollyApplySet;
+
+/// Last part of [`DollyUpdateSet`], systems that adjust the cameras after the rigs have been applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct DollyPostProcessSet;
+
+/// This plugin configures the dolly system sets.
+/// Add it and register a [`Dolly`] system per marker component,
+/// either through [`DollyComponent`] or by hand:
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_dolly::prelude::*;
+///
+/// #[derive(Component)]
+/// struct MainCamera;
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, DollyPlugin))
+///         .add_systems(
+///             PostUpdate,
+///             Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+///         )
+///         .run();
+/// }
+/// ```
+pub struct DollyPlugin;
+impl Plugin for DollyPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            (
+                DollyUpdateSet
+                    .before(TransformSystem::TransformPropagate)
+                    .before(CameraUpdateSystem),
+                (DollyDriveSet, DollyApplySet, DollyPostProcessSet)
+                    .chain()
+                    .in_set(DollyUpdateSet),
+            ),
+        )
+        .register_type::<Rig>()
+        .add_event::<RigBlendStarted>()
+        .add_event::<RigBlendFinished>()
+        .add_event::<SequenceMarkerReached>()
+        .add_event::<SequenceFinished>()
+        .add_systems(FixedLast, crate::timestep::record_fixed_step_targets)
+        .add_systems(
+            PostUpdate,
+            (
+                crate::timestep::interpolate_fixed_step_targets
+                    .in_set(DollyUpdateSet)
+                    .before(DollyDriveSet),
+                (
+                    apply_driver_states,
+                    crate::brain::update_brains,
+                    crate::sequence::play_sequences,
+                )
+                    .in_set(DollyDriveSet),
+                (crate::blend::update_rig_blends, capture_driver_states)
+                    .in_set(DollyPostProcessSet),
+            ),
+        );
+
+        if app.is_plugin_added::<AssetPlugin>() {
+            register_assets(app);
+        }
+
+        #[cfg(feature = "drivers")]
+        app.add_event::<AddTrauma>().add_systems(
+            PostUpdate,
+            (
+                (
+                    crate::drivers::entity::resolve_entity_drivers,
+                    crate::drivers::look_ahead::resolve_look_ahead,
+                    crate::drivers::platform_snap::resolve_platform_snaps,
+                    crate::drivers::limits::apply_yaw_pitch_limits,
+                    crate::drivers::shake::apply_trauma,
+                    crate::drivers::confiner::resolve_confiner_views,
+                    crate::drivers::framing::resolve_framing,
+                    crate::drivers::target_group::resolve_target_groups,
+                    crate::drivers::track::resolve_dolly_tracks,
+                )
+                    .in_set(DollyDriveSet),
+                crate::drivers::target_group::apply_target_group_scale.in_set(DollyPostProcessSet),
+            ),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Camera sequences and rig descriptions need an asset server,
+        // apps built from `MinimalPlugins` can do without
+        if app.world().contains_resource::<AssetServer>() {
+            register_assets(app);
+        }
+    }
+}
+
+fn register_assets(app: &mut App) {
+    if app.world().contains_resource::<Assets<CameraSequence>>() {
+        return;
+    }
+    app.init_asset::<CameraSequence>();
+
+    #[cfg(feature = "asset")]
+    app.init_asset_loader::<crate::sequence::CameraSequenceLoader>()
+        .init_asset::<crate::rig_asset::RigDescription>()
+        .init_asset_loader::<crate::rig_asset::RigDescriptionLoader>()
+        .add_systems(
+            PostUpdate,
+            crate::rig_asset::build_described_rigs.in_set(DollyDriveSet),
+        );
+}
+
+/// Transforms of the entities rigs follow or look at, read with [`entity_transform`]
+pub(crate) type EntityTransforms<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        &'static GlobalTransform,
+        Option<&'static Parent>,
+        Option<&'static FixedStepTarget>,
+    ),
+>;
+
+/// Transform of an entity in world space, `None` if it doesn't exist (anymore).
+///
+/// Root entities use this frame's [`Transform`]. Children, and every entity with
+/// `use_global_transform`, use the [`GlobalTransform`]. It isn't propagated yet when rigs
+/// are resolved, so it lags a frame behind. A [`FixedStepTarget`] is read at its
+/// interpolated transform instead, placed under the [`GlobalTransform`] of its parent.
+pub(crate) fn entity_transform(
+    transforms: &EntityTransforms,
+    entity: Entity,
+    use_global_transform: bool,
+) -> Option<Transform> {
+    let (local, global, parent, fixed_step) = transforms.get(entity).ok()?;
+    let Some(interpolated) = fixed_step.and_then(FixedStepTarget::interpolated_transform) else {
+        return Some(if use_global_transform || parent.is_some() {
+            global.compute_transform()
+        } else {
+            *local
+        });
+    };
+    let parent_global = parent.and_then(|parent| transforms.get(parent.get()).ok());
+    Some(match parent_global {
+        Some((_, parent_global, ..)) => parent_global
+            .mul_transform(interpolated)
+            .compute_transform(),
+        None => interpolated,
+    })
+}
+
+/// Writes edits of [`Rig::driver_states`], ie. made in an inspector, back to the drivers
+fn apply_driver_states(mut rigs: Query<&mut Rig, Changed<Rig>>) {
+    for mut rig in &mut rigs {
+        if rig.driver_states_edited() {
+            rig.apply_driver_states();
+        }
+    }
+}
+
+/// Keeps [`Rig::driver_states`] up to date without flagging the rigs as changed.
+/// Drivers are only edited through a changed rig, so unchanged rigs are skipped.
+fn capture_driver_states(mut rigs: Query<&mut Rig, Changed<Rig>>) {
+    for mut rig in &mut rigs {
+        rig.bypass_change_detection().capture_driver_states();
+    }
+}
+
 pub trait DollyComponent {
     fn add_dolly_component<T: Component>(&mut self, _: T) -> &mut Self;
     fn add_dolly_2d_component<T: Component>(&mut self, _: T) -> &mut Self;
@@ -14,27 +203,128 @@ pub trait DollyComponent {
 
 impl DollyComponent for App {
     fn add_dolly_component<T: Component>(&mut self, _: T) -> &mut Self {
-        self.add_systems(
-            Update,
-            Dolly::<T>::update_active_continuous.in_set(DollyUpdateSet),
+        add_dolly_plugin(self).add_systems(
+            PostUpdate,
+            Dolly::<T>::update_active_continuous.in_set(DollyApplySet),
         )
     }
 
     fn add_rig_component<T: Component>(&mut self, _: T) -> &mut Self {
-        self.add_systems(
-            Update,
-            Dolly::<T>::update_all_continuous.in_set(DollyUpdateSet),
+        add_dolly_plugin(self).add_systems(
+            PostUpdate,
+            Dolly::<T>::update_all_continuous.in_set(DollyApplySet),
         )
     }
 
     fn add_dolly_2d_component<T: Component>(&mut self, _: T) -> &mut Self {
-        self.add_systems(
-            Update,
-            Dolly::<T>::update_2d_active_continuous.in_set(DollyUpdateSet),
+        add_dolly_plugin(self).add_systems(
+            PostUpdate,
+            Dolly::<T>::update_2d_active_continuous.in_set(DollyApplySet),
         )
     }
 }
 
+pub(crate) fn add_dolly_plugin(app: &mut App) -> &mut App {
+    if !app.is_plugin_added::<DollyPlugin>() {
+        app.add_plugins(DollyPlugin);
+    }
+    app
+}
+
+/// Every rig of type `R` with marker `T` and the [`RigTarget`] it is bound to, if any.
+type RigBindings<'w, 's, T, R = Rig> =
+    Query<'w, 's, (Entity, Option<&'static RigTarget>), (With<R>, With<T>)>;
+
+/// Which of the marked entities a single rig writes its transform to.
+enum RigBinding<'a> {
+    /// The rig's [`RigTarget`], or the rig's own entity if it is one of the marked entities.
+    Bound(Entity),
+    /// Every marked entity that no rig is bound to.
+    Broadcast(&'a EntityHashSet),
+    /// Nothing, the rig has a [`RigPriority`] and waits for a [`DollyBrain`] to bind it.
+    Unbound,
+}
+
+impl RigBinding<'_> {
+    fn drives(&self, entity: Entity) -> bool {
+        match self {
+            RigBinding::Bound(target) => *target == entity,
+            RigBinding::Broadcast(bound) => !bound.contains(&entity),
+            RigBinding::Unbound => false,
+        }
+    }
+}
+
+/// The cameras rigs write to, for systems that work on the camera of a rig without knowing
+/// the marker of the [`Dolly`] systems that apply it.
+///
+/// Follows the same rules: a rig drives its [`RigTarget`], else the camera it is on,
+/// else every camera no other rig is bound to, unless it has a [`RigPriority`].
+#[derive(SystemParam)]
+pub(crate) struct RigCameras<'w, 's> {
+    cameras: Query<'w, 's, (Entity, &'static Camera)>,
+    #[allow(clippy::type_complexity)]
+    rigs: Query<
+        'w,
+        's,
+        (Entity, Option<&'static RigTarget>, Has<RigPriority>),
+        Or<(With<Rig>, With<Rig2d>)>,
+    >,
+}
+
+impl RigCameras<'_, '_> {
+    /// Every camera a rig drives
+    pub(crate) fn cameras(&self, rig: Entity) -> Vec<Entity> {
+        let Ok((_, target, has_priority)) = self.rigs.get(rig) else {
+            return Vec::new();
+        };
+        match target {
+            Some(target) if self.cameras.contains(target.0) => vec![target.0],
+            Some(_) => Vec::new(),
+            None if self.cameras.contains(rig) => vec![rig],
+            None if has_priority => Vec::new(),
+            None => {
+                let bound: EntityHashSet = self
+                    .rigs
+                    .iter()
+                    .filter_map(|(entity, target, _)| match target {
+                        Some(target) => Some(target.0),
+                        None => self.cameras.contains(entity).then_some(entity),
+                    })
+                    .collect();
+                self.cameras
+                    .iter()
+                    .map(|(entity, _)| entity)
+                    .filter(|entity| !bound.contains(entity))
+                    .collect()
+            }
+        }
+    }
+
+    /// The camera a rig drives, the first active one of a rig that drives several
+    #[cfg(any(feature = "drivers", feature = "helpers"))]
+    pub(crate) fn camera(&self, rig: Entity) -> Option<Entity> {
+        let cameras = self.cameras(rig);
+        cameras
+            .iter()
+            .copied()
+            .find(|&entity| {
+                self.cameras
+                    .get(entity)
+                    .is_ok_and(|(_, camera)| camera.is_active)
+            })
+            .or(cameras.first().copied())
+    }
+
+    /// Whether a rig waits for a [`DollyBrain`] to bind it to a camera
+    #[cfg(feature = "drivers")]
+    pub(crate) fn is_waiting(&self, rig: Entity) -> bool {
+        self.rigs
+            .get(rig)
+            .is_ok_and(|(_, target, has_priority)| target.is_none() && has_priority)
+    }
+}
+
 pub struct Dolly<T: Component> {
     _phantom: PhantomData<T>,
 }
@@ -43,47 +333,91 @@ impl<T> Dolly<T>
 where
     T: Component,
 {
+    /// Collects the entities that are driven by a specific rig, so broadcasting rigs leave them alone.
+    fn bound_entities<R: Component>(
+        bindings: &RigBindings<T, R>,
+        is_marked: impl Fn(Entity) -> bool,
+    ) -> EntityHashSet {
+        bindings
+            .iter()
+            .filter_map(|(entity, target)| match target {
+                Some(target) => Some(target.0),
+                None => is_marked(entity).then_some(entity),
+            })
+            .collect()
+    }
+
+    fn binding<'a>(
+        entity: Entity,
+        target: Option<&RigTarget>,
+        is_marked: bool,
+        has_priority: bool,
+        bound: &'a EntityHashSet,
+    ) -> RigBinding<'a> {
+        match target {
+            Some(target) => RigBinding::Bound(target.0),
+            None if is_marked => RigBinding::Bound(entity),
+            None if has_priority => RigBinding::Unbound,
+            None => RigBinding::Broadcast(bound),
+        }
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn update_active(
-        mut cameras: Query<(&mut Transform, &Camera), With<T>>,
+        mut cameras: Query<(Entity, &mut Transform, Option<&mut Projection>, &Camera), With<T>>,
         time: Res<Time>,
-        mut query: Query<&mut Rig, (Changed<Rig>, With<T>)>,
+        mut query: Query<
+            (Entity, &mut Rig, Option<&RigTarget>, Has<RigPriority>),
+            (Changed<Rig>, With<T>),
+        >,
+        bindings: RigBindings<T>,
     ) {
-        for mut rig in &mut query {
+        let bound = Self::bound_entities(&bindings, |e| cameras.contains(e));
+        for (entity, mut rig, target, has_priority) in &mut query {
             //info!("{:?} changed: {:?}", entity, d);
 
             let transform = rig.update(time.delta_secs());
+            let lens = rig.final_lens;
+            let binding = Self::binding(
+                entity,
+                target,
+                cameras.contains(entity),
+                has_priority,
+                &bound,
+            );
 
-            cameras.iter_mut().for_each(|(mut t, camera)| {
-                if camera.is_active {
-                    *t = transform;
-                }
-            });
+            cameras
+                .iter_mut()
+                .for_each(|(e, mut t, projection, camera)| {
+                    if camera.is_active && binding.drives(e) {
+                        *t = transform;
+                        // Rigs without lens drivers leave the projection alone
+                        if let Some(mut projection) = projection.filter(|_| !lens.is_empty()) {
+                            lens.apply_to(&mut projection);
+                        }
+                    }
+                });
         }
     }
 
-    const SCALE_INCR_THRESHOLD: f32 = 0.0025;
-    const RANGE_SCALE_2D: f32 = 1. + Self::SCALE_INCR_THRESHOLD;
-
+    /// Updates [`Rig2d`]s, writing position and roll to the camera's transform
+    /// and zoom to its orthographic scale. The camera's Z translation is kept.
+    #[allow(clippy::type_complexity)]
     pub fn update_2d_active(
-        mut cameras: Query<(&mut Transform, &mut OrthographicProjection, &Camera), With<T>>,
+        mut cameras: Query<(Entity, &mut Transform, &mut OrthographicProjection, &Camera), With<T>>,
         time: Res<Time>,
-        mut query: Query<&mut Rig, (Changed<Rig>, With<T>)>,
+        mut query: Query<(Entity, &mut Rig2d, Option<&RigTarget>), (Changed<Rig2d>, With<T>)>,
+        bindings: RigBindings<T, Rig2d>,
     ) {
-        for mut rig in &mut query {
-            let mut transform = rig.update(time.delta_secs());
-            cameras.iter_mut().for_each(|(mut t, mut orth, camera)| {
-                if camera.is_active {
-                    //Bind camera's Z axis to scale, if used for init state check to prevent scale of 0
-                    if !(transform.translation.z < Self::RANGE_SCALE_2D
-                        && transform.translation.z > -Self::RANGE_SCALE_2D)
-                    {
-                        orth.scale = (transform.translation.z + 1.) * Self::SCALE_INCR_THRESHOLD;
-                    }
-                    //Drop Z from camera's transform calculations and keep original
-                    let xy = transform.translation.truncate().extend(t.translation.z);
-                    transform.translation = xy;
-                    *t = transform;
+        let bound = Self::bound_entities(&bindings, |e| cameras.contains(e));
+        for (entity, mut rig, target) in &mut query {
+            let transform = rig.update(time.delta_secs());
+            // Brains only pick 3D rigs
+            let binding = Self::binding(entity, target, cameras.contains(entity), false, &bound);
+            cameras.iter_mut().for_each(|(e, mut t, mut orth, camera)| {
+                if camera.is_active && binding.drives(e) {
+                    transform.apply_to(&mut t);
+                    orth.scale = transform.scale();
                 }
             });
         }
@@ -91,17 +425,31 @@ where
 
     #[allow(clippy::type_complexity)]
     pub fn update_all(
-        mut transforms: Query<&mut Transform, With<T>>,
+        mut transforms: Query<(Entity, &mut Transform), With<T>>,
         time: Res<Time>,
-        mut query: Query<&mut Rig, (Changed<Rig>, With<T>)>,
+        mut query: Query<
+            (Entity, &mut Rig, Option<&RigTarget>, Has<RigPriority>),
+            (Changed<Rig>, With<T>),
+        >,
+        bindings: RigBindings<T>,
     ) {
-        for mut rig in &mut query {
+        let bound = Self::bound_entities(&bindings, |e| transforms.contains(e));
+        for (entity, mut rig, target, has_priority) in &mut query {
             //info!("{:?} changed: {:?}", entity, d);
 
             let transform = rig.update(time.delta_secs());
+            let binding = Self::binding(
+                entity,
+                target,
+                transforms.contains(entity),
+                has_priority,
+                &bound,
+            );
 
-            transforms.iter_mut().for_each(|mut t| {
-                *t = transform;
+            transforms.iter_mut().for_each(|(e, mut t)| {
+                if binding.drives(e) {
+                    *t = transform;
+                }
             });
         }
     }
@@ -112,61 +460,86 @@ where
     // However, if camera movement is expected to last after input, ie. using large amount of motion smoothing < 0.25
     // Use the systems below instead.
 
+    #[allow(clippy::type_complexity)]
     pub fn update_active_continuous(
-        mut cameras: Query<(&mut Transform, &Camera), With<T>>,
+        mut cameras: Query<(Entity, &mut Transform, Option<&mut Projection>, &Camera), With<T>>,
         time: Res<Time>,
-        mut query: Query<&mut Rig, With<T>>,
+        mut query: Query<(Entity, &mut Rig, Option<&RigTarget>, Has<RigPriority>), With<T>>,
+        bindings: RigBindings<T>,
     ) {
-        for mut rig in &mut query {
+        let bound = Self::bound_entities(&bindings, |e| cameras.contains(e));
+        for (entity, mut rig, target, has_priority) in &mut query {
             //info!("{:?} changed: {:?}", entity, d);
 
             let transform = rig.update(time.delta_secs());
+            let lens = rig.final_lens;
+            let binding = Self::binding(
+                entity,
+                target,
+                cameras.contains(entity),
+                has_priority,
+                &bound,
+            );
 
-            cameras.iter_mut().for_each(|(mut t, camera)| {
-                if camera.is_active {
-                    *t = transform;
-                }
-            });
+            cameras
+                .iter_mut()
+                .for_each(|(e, mut t, projection, camera)| {
+                    if camera.is_active && binding.drives(e) {
+                        *t = transform;
+                        // Rigs without lens drivers leave the projection alone
+                        if let Some(mut projection) = projection.filter(|_| !lens.is_empty()) {
+                            lens.apply_to(&mut projection);
+                        }
+                    }
+                });
         }
     }
 
     #[allow(clippy::type_complexity)]
     pub fn update_2d_active_continuous(
-        mut cameras: Query<(&mut Transform, &mut OrthographicProjection, &Camera), With<T>>,
+        mut cameras: Query<(Entity, &mut Transform, &mut OrthographicProjection, &Camera), With<T>>,
         time: Res<Time>,
-        mut query: Query<&mut Rig, With<T>>,
+        mut query: Query<(Entity, &mut Rig2d, Option<&RigTarget>), With<T>>,
+        bindings: RigBindings<T, Rig2d>,
     ) {
-        for mut rig in &mut query {
-            let mut transform = rig.update(time.delta_secs());
-            cameras.iter_mut().for_each(|(mut t, mut orth, camera)| {
-                if camera.is_active {
-                    //Bind camera's Z axis to scale, if used for init state check to prevent scale of 0
-                    if !(transform.translation.z < Self::RANGE_SCALE_2D
-                        && transform.translation.z > -Self::RANGE_SCALE_2D)
-                    {
-                        orth.scale = (transform.translation.z + 1.) * Self::SCALE_INCR_THRESHOLD;
-                    }
-                    //Drop Z from camera's transform calculations and keep original
-                    let xy = transform.translation.truncate().extend(t.translation.z);
-                    transform.translation = xy;
-                    *t = transform;
+        let bound = Self::bound_entities(&bindings, |e| cameras.contains(e));
+        for (entity, mut rig, target) in &mut query {
+            let transform = rig.update(time.delta_secs());
+            // Brains only pick 3D rigs
+            let binding = Self::binding(entity, target, cameras.contains(entity), false, &bound);
+            cameras.iter_mut().for_each(|(e, mut t, mut orth, camera)| {
+                if camera.is_active && binding.drives(e) {
+                    transform.apply_to(&mut t);
+                    orth.scale = transform.scale();
                 }
             });
         }
     }
 
+    #[allow(clippy::type_complexity)]
     pub fn update_all_continuous(
-        mut transforms: Query<&mut Transform, With<T>>,
+        mut transforms: Query<(Entity, &mut Transform), With<T>>,
         time: Res<Time>,
-        mut query: Query<&mut Rig, With<T>>,
+        mut query: Query<(Entity, &mut Rig, Option<&RigTarget>, Has<RigPriority>), With<T>>,
+        bindings: RigBindings<T>,
     ) {
-        for mut rig in &mut query {
+        let bound = Self::bound_entities(&bindings, |e| transforms.contains(e));
+        for (entity, mut rig, target, has_priority) in &mut query {
             //info!("{:?} changed: {:?}", entity, d);
 
             let transform = rig.update(time.delta_secs());
+            let binding = Self::binding(
+                entity,
+                target,
+                transforms.contains(entity),
+                has_priority,
+                &bound,
+            );
 
-            transforms.iter_mut().for_each(|mut t| {
-                *t = transform;
+            transforms.iter_mut().for_each(|(e, mut t)| {
+                if binding.drives(e) {
+                    *t = transform;
+                }
             });
         }
     }
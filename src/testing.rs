@@ -0,0 +1,203 @@
+//! Utilities for testing rigs and the systems driving them without a window or renderer.
+//!
+//! [`DollyTestApp`] steps an [`App`] with [`MinimalPlugins`] and a fixed frame time, so rigs
+//! behave the same on every run. [`simulate_rig`] and [`simulate_rig_2d`] run a rig on its own,
+//! and [`snapshot`] turns recorded transforms into text to compare against.
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_dolly::{prelude::*, testing::*};
+//!
+//! #[derive(Component)]
+//! struct MainCamera;
+//!
+//! # fn main() {
+//! // In a #[test] function
+//! let mut test = DollyTestApp::new();
+//! test.app.add_dolly_component(MainCamera);
+//! let camera = test.spawn((
+//!     MainCamera,
+//!     Camera::default(),
+//!     Rig::builder().with(Position::new(Vec3::X)).build(),
+//! ));
+//! test.run_frames(2);
+//! assert_transform_near(test.transform(camera), Transform::from_xyz(1., 0., 0.), 1e-5);
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+
+use crate::prelude::*;
+
+/// Frame time of a [`DollyTestApp`] unless set otherwise, 60 frames per second
+pub const DEFAULT_DELTA_SECONDS: f32 = 1. / 60.;
+
+/// A headless [`App`] with [`DollyPlugin`] that advances time by a fixed delta every frame.
+///
+/// The app is updated once when created, so time has started and every frame after it
+/// advances by the whole delta. Add systems, ie. the [`Dolly`] update systems, through [`DollyTestApp::app`].
+pub struct DollyTestApp {
+    pub app: App,
+    delta: Duration,
+}
+
+impl Default for DollyTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DollyTestApp {
+    pub fn new() -> Self {
+        Self::with_delta_seconds(DEFAULT_DELTA_SECONDS)
+    }
+
+    pub fn with_delta_seconds(delta_seconds: f32) -> Self {
+        let delta = Duration::from_secs_f32(delta_seconds);
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, DollyPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(delta));
+        app.update();
+        Self { app, delta }
+    }
+
+    /// Time every frame advances by
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    /// Changes the time the next frames advance by, ie. to simulate a frame spike.
+    /// Frames longer than [`Time<Virtual>::max_delta`], 0.25 seconds by default, are clamped to it.
+    pub fn set_delta_seconds(&mut self, delta_seconds: f32) {
+        self.delta = Duration::from_secs_f32(delta_seconds);
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(self.delta));
+    }
+
+    pub fn world(&self) -> &World {
+        self.app.world()
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    pub fn spawn(&mut self, bundle: impl Bundle) -> Entity {
+        self.world_mut().spawn(bundle).id()
+    }
+
+    /// Updates the app once
+    pub fn step(&mut self) {
+        self.app.update();
+    }
+
+    pub fn run_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    /// The entity's [`Transform`], panics if it has none
+    #[track_caller]
+    pub fn transform(&self, entity: Entity) -> Transform {
+        *self
+            .world()
+            .get::<Transform>(entity)
+            .unwrap_or_else(|| panic!("{entity} has no Transform"))
+    }
+
+    /// Runs a number of frames, recording the entity's [`Transform`] after each of them
+    pub fn record(&mut self, entity: Entity, frames: usize) -> Vec<Transform> {
+        (0..frames)
+            .map(|_| {
+                self.step();
+                self.transform(entity)
+            })
+            .collect()
+    }
+}
+
+/// Updates a [`Rig`] a number of times, returning the transform after each update
+pub fn simulate_rig(rig: &mut Rig, frames: usize, delta_seconds: f32) -> Vec<Transform> {
+    (0..frames).map(|_| rig.update(delta_seconds)).collect()
+}
+
+/// Updates a [`Rig2d`] a number of times, returning the transform after each update
+pub fn simulate_rig_2d(rig: &mut Rig2d, frames: usize, delta_seconds: f32) -> Vec<Transform2d> {
+    (0..frames).map(|_| rig.update(delta_seconds)).collect()
+}
+
+/// Panics unless the translations, rotations and scales of two transforms are within `epsilon`.
+/// Rotations that are the same but of opposite sign count as equal.
+#[track_caller]
+pub fn assert_transform_near(actual: Transform, expected: Transform, epsilon: f32) {
+    let rotation = if actual.rotation.dot(expected.rotation) < 0. {
+        -actual.rotation
+    } else {
+        actual.rotation
+    };
+    assert!(
+        actual
+            .translation
+            .abs_diff_eq(expected.translation, epsilon)
+            && rotation.abs_diff_eq(expected.rotation, epsilon)
+            && actual.scale.abs_diff_eq(expected.scale, epsilon),
+        "transforms differ by more than {epsilon}\n  actual: {}\nexpected: {}",
+        format_transform(&actual),
+        format_transform(&expected),
+    );
+}
+
+/// Panics unless the positions, rolls and zooms of two 2D transforms are within `epsilon`
+#[track_caller]
+pub fn assert_transform_2d_near(actual: Transform2d, expected: Transform2d, epsilon: f32) {
+    assert!(
+        actual.position.abs_diff_eq(expected.position, epsilon)
+            && (actual.roll - expected.roll).abs() <= epsilon
+            && (actual.zoom - expected.zoom).abs() <= epsilon,
+        "transforms differ by more than {epsilon}\n  actual: {}\nexpected: {}",
+        format_transform_2d(&actual),
+        format_transform_2d(&expected),
+    );
+}
+
+/// One line per transform with translation, rotation and scale rounded to 3 decimals,
+/// to compare recorded motion against a snapshot written out in the test
+pub fn snapshot(transforms: &[Transform]) -> String {
+    transforms
+        .iter()
+        .map(format_transform)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Like [`snapshot`], for 2D transforms
+pub fn snapshot_2d(transforms: &[Transform2d]) -> String {
+    transforms
+        .iter()
+        .map(format_transform_2d)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Rounds to 3 decimals, without printing negative zero
+fn round(value: f32) -> f32 {
+    (value * 1000.).round() / 1000. + 0.
+}
+
+fn format_transform(transform: &Transform) -> String {
+    let [x, y, z] = transform.translation.to_array().map(round);
+    let [rx, ry, rz, rw] = transform.rotation.to_array().map(round);
+    let [sx, sy, sz] = transform.scale.to_array().map(round);
+    format!("t: {x} {y} {z} | r: {rx} {ry} {rz} {rw} | s: {sx} {sy} {sz}")
+}
+
+fn format_transform_2d(transform: &Transform2d) -> String {
+    let [x, y] = transform.position.to_array().map(round);
+    format!(
+        "p: {x} {y} | roll: {} | zoom: {}",
+        round(transform.roll),
+        round(transform.zoom)
+    )
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+/// Splits long frames into several updates of a rig, so smoothing and other drivers that
+/// depend on the frame time behave the same under frame spikes.
+/// Set with [`RigBuilder::with_substeps`](crate::dolly_type::RigBuilder::with_substeps) or
+/// [`Rig2dBuilder::with_substeps`](crate::rig_2d::Rig2dBuilder::with_substeps).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct Substeps {
+    /// Longest update, in seconds
+    pub max_step_seconds: f32,
+    /// Most updates per frame, longer frames take longer steps rather than losing time
+    pub max_substeps: u32,
+}
+
+impl Substeps {
+    pub fn new(max_step_seconds: f32, max_substeps: u32) -> Self {
+        Self {
+            max_step_seconds,
+            max_substeps,
+        }
+    }
+
+    /// Number of updates for a frame, and the time of each of them
+    pub fn split(&self, delta_time_seconds: f32) -> (u32, f32) {
+        if self.max_step_seconds <= 0. || delta_time_seconds <= self.max_step_seconds {
+            return (1, delta_time_seconds);
+        }
+        let steps = ((delta_time_seconds / self.max_step_seconds).ceil() as u32)
+            .clamp(1, self.max_substeps.max(1));
+        (steps, delta_time_seconds / steps as f32)
+    }
+}
+
+/// Marks an entity moved in [`FixedUpdate`], ie. by physics, so rigs follow it without jitter.
+///
+/// The transforms at the end of the last two fixed steps are recorded, and before the rigs are
+/// evaluated a transform in between them is computed by [`Time<Fixed>::overstep_fraction`].
+/// Drivers reading the entity, ie. `FollowEntity` or `LookAtEntity`, follow that interpolated
+/// transform, while the entity's own [`Transform`] is left to the fixed-step systems.
+/// To render the entity smoothly as well, copy [`FixedStepTarget::interpolated_transform`] to
+/// a child holding its visuals. Move the entity only in [`FixedUpdate`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+#[require(Transform)]
+pub struct FixedStepTarget {
+    start: Option<Transform>,
+    end: Option<Transform>,
+    interpolated: Option<Transform>,
+}
+
+impl FixedStepTarget {
+    /// Transform at the end of the last fixed step
+    pub fn fixed_transform(&self) -> Option<Transform> {
+        self.end
+    }
+
+    /// Transform in between the last two fixed steps for this frame, what rigs follow
+    pub fn interpolated_transform(&self) -> Option<Transform> {
+        self.interpolated
+    }
+}
+
+/// Records the transform at the end of every fixed step
+pub(crate) fn record_fixed_step_targets(mut targets: Query<(&mut FixedStepTarget, &Transform)>) {
+    for (mut target, transform) in &mut targets {
+        target.start = target.end.or(Some(*transform));
+        target.end = Some(*transform);
+    }
+}
+
+/// Interpolates in between the last two fixed steps for the rigs of this frame
+pub(crate) fn interpolate_fixed_step_targets(
+    time: Res<Time<Fixed>>,
+    mut targets: Query<&mut FixedStepTarget>,
+) {
+    let overstep = time.overstep_fraction();
+    for mut target in &mut targets {
+        let (Some(start), Some(end)) = (target.start, target.end) else {
+            continue;
+        };
+        target.interpolated = Some(Transform {
+            translation: start.translation.lerp(end.translation, overstep),
+            rotation: start.rotation.slerp(end.rotation, overstep),
+            scale: start.scale.lerp(end.scale, overstep),
+        });
+    }
+}
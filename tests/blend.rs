@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+#[test]
+fn rig_blend_moves_between_rigs_and_binds_the_target() {
+    let mut test = DollyTestApp::with_delta_seconds(0.25);
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((MainCamera, Camera::default()));
+    let from = test.spawn((
+        MainCamera,
+        Transform::default(),
+        RigTarget(camera),
+        Rig::builder().with(Position::new(Vec3::ZERO)).build(),
+    ));
+    let to = test.spawn((
+        MainCamera,
+        Transform::default(),
+        Rig::builder().with(Position::new(Vec3::X * 4.)).build(),
+    ));
+    test.step();
+
+    let mut finished = test
+        .world_mut()
+        .resource_mut::<Events<RigBlendFinished>>()
+        .get_cursor();
+    test.world_mut()
+        .entity_mut(camera)
+        .insert(RigBlend::between(from, to, 1.));
+    test.run_frames(2);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(2., 0., 0.),
+        1e-5,
+    );
+
+    test.run_frames(2);
+    let events = test.world().resource::<Events<RigBlendFinished>>();
+    assert_eq!(
+        finished.read(events).copied().collect::<Vec<_>>(),
+        [RigBlendFinished {
+            camera,
+            to,
+            interrupted: false,
+        }]
+    );
+    assert!(test.world().get::<RigBlend>(camera).is_none());
+    assert_eq!(test.world().get::<RigTarget>(to), Some(&RigTarget(camera)));
+    assert!(test.world().get::<RigTarget>(from).is_none());
+
+    // The camera stays with the rig it blended to
+    test.step();
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(4., 0., 0.),
+        1e-5,
+    );
+}
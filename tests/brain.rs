@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+#[test]
+fn brain_cuts_to_the_highest_priority_then_blends() {
+    let mut test = DollyTestApp::with_delta_seconds(0.25);
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((MainCamera, Camera::default(), DollyBrain::new(1.)));
+    let high = test.spawn((
+        MainCamera,
+        Transform::default(),
+        RigPriority::new(1),
+        Rig::builder().with(Position::new(Vec3::X * 4.)).build(),
+    ));
+    let low = test.spawn((
+        MainCamera,
+        Transform::default(),
+        RigPriority::new(0),
+        Rig::builder().with(Position::new(Vec3::Y * 4.)).build(),
+    ));
+
+    // The first live rig is cut to
+    test.step();
+    let live = |test: &DollyTestApp| test.world().get::<DollyBrain>(camera).unwrap().live();
+    assert_eq!(live(&test), Some(high));
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(4., 0., 0.),
+        1e-5,
+    );
+
+    // A new live rig is blended to
+    test.world_mut()
+        .get_mut::<RigPriority>(low)
+        .unwrap()
+        .priority = 2;
+    test.run_frames(2);
+    assert_eq!(live(&test), Some(low));
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(2., 2., 0.),
+        1e-5,
+    );
+
+    test.run_frames(3);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(0., 4., 0.),
+        1e-5,
+    );
+    assert_eq!(test.world().get::<RigTarget>(low), Some(&RigTarget(camera)));
+    assert!(test.world().get::<RigTarget>(high).is_none());
+}
+
+#[test]
+fn brain_cuts_to_a_rig_spawned_before_its_camera() {
+    let mut test = DollyTestApp::new();
+    test.app.add_systems(
+        PostUpdate,
+        Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+    );
+    test.spawn((
+        MainCamera,
+        RigPriority::new(0),
+        Rig::builder().with(Position::new(Vec3::X * 4.)).build(),
+    ));
+    // The rig doesn't change anymore once the camera shows up
+    test.run_frames(2);
+    let camera = test.spawn((MainCamera, Camera::default(), DollyBrain::new(1.)));
+
+    test.step();
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(4., 0., 0.),
+        1e-5,
+    );
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "drivers")]
+
+use bevy::{prelude::*, render::primitives::Aabb};
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+#[test]
+fn collision_arm_keeps_the_camera_in_front_of_walls() {
+    let mut test = DollyTestApp::new();
+    test.app
+        .add_plugins(DollyCollisionPlugin::<AabbCollisionBackend>::default())
+        .add_dolly_component(MainCamera);
+    let wall = test.spawn((
+        Transform::from_xyz(0., 0., 3.),
+        Aabb::from_min_max(Vec3::new(-5., -5., -0.5), Vec3::new(5., 5., 0.5)),
+    ));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Position::new(Vec3::ZERO))
+            .with(CollisionArm::new(Vec3::Z * 6.).with_min_distance(0.5))
+            .build(),
+    ));
+    test.run_frames(3);
+
+    // Pulled in right away, in front of the wall by the radius of the cast
+    let z = test.transform(camera).translation.z;
+    assert!((z - 2.3).abs() < 1e-3, "{z}");
+
+    // Eases back out once the way is clear
+    test.world_mut().despawn(wall);
+    test.step();
+    let z = test.transform(camera).translation.z;
+    assert!(z > 2.3 && z < 6., "{z}");
+    test.run_frames(300);
+    let z = test.transform(camera).translation.z;
+    assert!((z - 6.).abs() < 1e-3, "{z}");
+}
+
+#[test]
+fn collision_arm_moves_broadcast_cameras_updated_on_change() {
+    let mut test = DollyTestApp::new();
+    test.app
+        .add_plugins(DollyCollisionPlugin::<AabbCollisionBackend>::default())
+        .add_systems(
+            PostUpdate,
+            Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+        );
+    test.spawn((
+        Transform::from_xyz(0., 0., 3.),
+        Aabb::from_min_max(Vec3::new(-5., -5., -0.5), Vec3::new(5., 5., 0.5)),
+    ));
+    let camera = test.spawn((MainCamera, Camera::default()));
+    // Not on the camera nor bound to it
+    test.spawn((
+        MainCamera,
+        Rig::builder()
+            .with(Position::new(Vec3::ZERO))
+            .with(CollisionArm::new(Vec3::Z * 6.).with_min_distance(0.5))
+            .build(),
+    ));
+
+    // The wall's GlobalTransform is propagated after the first frame
+    test.run_frames(2);
+    let z = test.transform(camera).translation.z;
+    assert!((z - 2.3).abs() < 1e-3, "{z}");
+
+    // Stays in front of the wall once nothing else changes the rig
+    test.run_frames(10);
+    let z = test.transform(camera).translation.z;
+    assert!((z - 2.3).abs() < 1e-3, "{z}");
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "drivers")]
+
+use bevy::{math::bounding::Aabb2d, prelude::*};
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn confined_camera(test: &mut DollyTestApp, bounds_half_size: Vec2) -> Entity {
+    test.app.add_dolly_2d_component(MainCamera);
+    test.spawn((
+        MainCamera,
+        Camera::default(),
+        OrthographicProjection {
+            area: Rect::new(-100., -50., 100., 50.),
+            ..OrthographicProjection::default_2d()
+        },
+        Rig2d::builder()
+            .with(Position2d::default())
+            .with(Confiner::new(ConfinerBounds::Rect(Aabb2d::new(
+                Vec2::ZERO,
+                bounds_half_size,
+            ))))
+            .build(),
+    ))
+}
+
+fn move_to(test: &mut DollyTestApp, camera: Entity, position: Vec2) {
+    test.world_mut()
+        .get_mut::<Rig2d>(camera)
+        .unwrap()
+        .driver_mut::<Position2d>()
+        .position = position;
+}
+
+#[test]
+fn confiner_keeps_the_view_inside_the_bounds() {
+    let mut test = DollyTestApp::new();
+    let camera = confined_camera(&mut test, Vec2::new(500., 200.));
+    test.step();
+
+    move_to(&mut test, camera, Vec2::new(1000., -1000.));
+    test.run_frames(2);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(400., -150., 0.),
+        1e-3,
+    );
+
+    move_to(&mut test, camera, Vec2::new(-100., 20.));
+    test.run_frames(2);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(-100., 20., 0.),
+        1e-3,
+    );
+}
+
+#[test]
+fn confiner_centers_views_larger_than_the_bounds() {
+    let mut test = DollyTestApp::new();
+    let camera = confined_camera(&mut test, Vec2::new(50., 20.));
+    move_to(&mut test, camera, Vec2::new(300., 300.));
+    test.run_frames(3);
+    assert_transform_near(test.transform(camera), Transform::IDENTITY, 1e-3);
+}
+
+#[test]
+fn broadcast_rigs_confine_to_the_view_of_their_camera() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_2d_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        OrthographicProjection {
+            area: Rect::new(-100., -50., 100., 50.),
+            ..OrthographicProjection::default_2d()
+        },
+    ));
+    // Not on the camera nor bound to it
+    let rig = test.spawn((
+        MainCamera,
+        Rig2d::builder()
+            .with(Position2d::default())
+            .with(Confiner::new(ConfinerBounds::Rect(Aabb2d::new(
+                Vec2::ZERO,
+                Vec2::new(300., 200.),
+            ))))
+            .build(),
+    ));
+    move_to(&mut test, rig, Vec2::new(1000., 0.));
+    test.run_frames(2);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(200., 0., 0.),
+        1e-3,
+    );
+}
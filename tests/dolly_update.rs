@@ -0,0 +1,274 @@
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+/// A rig that keeps moving after its position changes, until the smoothing catches up
+fn smoothed_rig() -> Rig {
+    Rig::builder()
+        .with(Position::new(Vec3::ZERO))
+        .with(Smooth::new_position(1.))
+        .build()
+}
+
+fn move_rig(test: &mut DollyTestApp, rig: Entity, position: Vec3) {
+    test.world_mut()
+        .get_mut::<Rig>(rig)
+        .unwrap()
+        .driver_mut::<Position>()
+        .position = position;
+}
+
+#[test]
+fn update_active_drives_active_cameras() {
+    let mut test = DollyTestApp::new();
+    test.app.add_systems(
+        PostUpdate,
+        Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+    );
+    let active = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder().with(Position::new(Vec3::X)).build(),
+    ));
+    let inactive = test.spawn((
+        MainCamera,
+        Camera {
+            is_active: false,
+            ..default()
+        },
+        Rig::builder().with(Position::new(Vec3::Y)).build(),
+    ));
+    test.step();
+
+    assert_transform_near(
+        test.transform(active),
+        Transform::from_xyz(1., 0., 0.),
+        1e-5,
+    );
+    assert_transform_near(test.transform(inactive), Transform::IDENTITY, 1e-5);
+}
+
+#[test]
+fn update_active_only_updates_changed_rigs() {
+    let mut test = DollyTestApp::new();
+    test.app.add_systems(
+        PostUpdate,
+        Dolly::<MainCamera>::update_active.in_set(DollyApplySet),
+    );
+    let camera = test.spawn((MainCamera, Camera::default(), smoothed_rig()));
+    test.step();
+
+    move_rig(&mut test, camera, Vec3::X * 10.);
+    test.step();
+    let moved = test.transform(camera);
+    assert!(moved.translation.x > 0. && moved.translation.x < 10.);
+
+    // Smoothing stops with the input
+    let frames = test.record(camera, 10);
+    assert!(frames.iter().all(|frame| *frame == moved));
+}
+
+#[test]
+fn update_active_continuous_keeps_smoothing() {
+    let mut test = DollyTestApp::new();
+    test.app.add_systems(
+        PostUpdate,
+        Dolly::<MainCamera>::update_active_continuous.in_set(DollyApplySet),
+    );
+    let camera = test.spawn((MainCamera, Camera::default(), smoothed_rig()));
+    test.step();
+
+    move_rig(&mut test, camera, Vec3::X * 10.);
+    let frames = test.record(camera, 120);
+    // Still moving after the frame of the input
+    assert!(frames[1].translation.x > frames[0].translation.x);
+    assert_transform_near(
+        *frames.last().unwrap(),
+        Transform::from_xyz(10., 0., 0.),
+        1e-3,
+    );
+}
+
+#[test]
+fn update_all_drives_entities_without_camera() {
+    let mut test = DollyTestApp::new();
+    test.app.add_systems(
+        PostUpdate,
+        Dolly::<MainCamera>::update_all.in_set(DollyApplySet),
+    );
+    let entity = test.spawn((MainCamera, Transform::default(), smoothed_rig()));
+    test.step();
+
+    move_rig(&mut test, entity, Vec3::Z * 5.);
+    test.step();
+    let moved = test.transform(entity);
+    assert!(moved.translation.z > 0. && moved.translation.z < 5.);
+    assert_eq!(test.record(entity, 5), vec![moved; 5]);
+}
+
+#[test]
+fn update_all_continuous_keeps_smoothing() {
+    let mut test = DollyTestApp::new();
+    test.app.add_rig_component(MainCamera);
+    let entity = test.spawn((MainCamera, Transform::default(), smoothed_rig()));
+    test.step();
+
+    move_rig(&mut test, entity, Vec3::Z * 5.);
+    test.run_frames(120);
+    assert_transform_near(
+        test.transform(entity),
+        Transform::from_xyz(0., 0., 5.),
+        1e-3,
+    );
+}
+
+#[test]
+fn rig_target_binds_a_rig_to_one_camera() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let bound = test.spawn((MainCamera, Camera::default()));
+    let other = test.spawn((MainCamera, Camera::default()));
+    test.spawn((
+        MainCamera,
+        RigTarget(bound),
+        Rig::builder().with(Position::new(Vec3::ONE)).build(),
+    ));
+    test.step();
+
+    assert_transform_near(
+        test.transform(bound),
+        Transform::from_translation(Vec3::ONE),
+        1e-5,
+    );
+    assert_transform_near(test.transform(other), Transform::IDENTITY, 1e-5);
+}
+
+fn smoothed_rig_2d() -> Rig2d {
+    Rig2d::builder()
+        .with(Position2d::default())
+        .with(Roll::default())
+        .with(Zoom::default())
+        .with(Smooth2d::new_position(1.))
+        .build()
+}
+
+fn camera_2d(z: f32) -> impl Bundle {
+    (
+        MainCamera,
+        Camera::default(),
+        OrthographicProjection::default_2d(),
+        Transform::from_xyz(0., 0., z),
+        smoothed_rig_2d(),
+    )
+}
+
+#[test]
+fn update_2d_active_writes_transform_and_scale() {
+    let mut test = DollyTestApp::new();
+    test.app.add_systems(
+        PostUpdate,
+        Dolly::<MainCamera>::update_2d_active.in_set(DollyApplySet),
+    );
+    let camera = test.spawn(camera_2d(100.));
+    test.step();
+
+    let mut rig = test.world_mut().get_mut::<Rig2d>(camera).unwrap();
+    rig.driver_mut::<Roll>().roll = 0.5;
+    rig.driver_mut::<Zoom>().zoom = 2.;
+    test.step();
+
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(0., 0., 100.).with_rotation(Quat::from_rotation_z(0.5)),
+        1e-5,
+    );
+    let projection = test.world().get::<OrthographicProjection>(camera).unwrap();
+    assert!((projection.scale - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn update_2d_active_only_updates_changed_rigs() {
+    let mut test = DollyTestApp::new();
+    test.app.add_systems(
+        PostUpdate,
+        Dolly::<MainCamera>::update_2d_active.in_set(DollyApplySet),
+    );
+    let camera = test.spawn(camera_2d(0.));
+    test.step();
+
+    test.world_mut()
+        .get_mut::<Rig2d>(camera)
+        .unwrap()
+        .driver_mut::<Position2d>()
+        .position = Vec2::new(100., 0.);
+    test.step();
+    let moved = test.transform(camera);
+    assert!(moved.translation.x > 0. && moved.translation.x < 100.);
+    assert_eq!(test.record(camera, 10), vec![moved; 10]);
+}
+
+#[test]
+fn update_2d_active_continuous_keeps_smoothing() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_2d_component(MainCamera);
+    let camera = test.spawn(camera_2d(0.));
+    test.step();
+
+    test.world_mut()
+        .get_mut::<Rig2d>(camera)
+        .unwrap()
+        .driver_mut::<Position2d>()
+        .position = Vec2::new(100., 0.);
+    test.run_frames(120);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(100., 0., 0.),
+        1e-2,
+    );
+}
+
+#[test]
+fn rigs_on_cameras_only_drive_their_own_camera() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let own = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder().with(Position::new(Vec3::X)).build(),
+    ));
+    let other = test.spawn((MainCamera, Camera::default()));
+    // Without a target a rig drives the marked cameras no other rig is bound to
+    test.spawn((
+        MainCamera,
+        Transform::default(),
+        Rig::builder().with(Position::new(Vec3::Y)).build(),
+    ));
+    test.step();
+
+    assert_transform_near(test.transform(own), Transform::from_xyz(1., 0., 0.), 1e-5);
+    assert_transform_near(test.transform(other), Transform::from_xyz(0., 1., 0.), 1e-5);
+}
+
+#[test]
+fn dolly_plugin_runs_before_transform_propagation() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder().with(Position::new(Vec3::X)).build(),
+    ));
+    let child = test.spawn(Transform::from_xyz(0., 1., 0.));
+    test.world_mut().entity_mut(camera).add_child(child);
+    test.step();
+
+    // Propagated in the same frame the rig moved the camera
+    let global = test.world().get::<GlobalTransform>(child).unwrap();
+    assert_transform_near(
+        global.compute_transform(),
+        Transform::from_xyz(1., 1., 0.),
+        1e-5,
+    );
+}
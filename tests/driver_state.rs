@@ -0,0 +1,80 @@
+use bevy::{prelude::*, reflect::GetPath};
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+#[derive(Debug)]
+struct Wobble;
+
+impl RigDriver for Wobble {
+    fn update(&mut self, params: RigUpdateParams) -> Transform {
+        *params.parent
+    }
+}
+
+fn camera_app() -> (DollyTestApp, Entity) {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Position::new(Vec3::X))
+            .with(Wobble)
+            .build(),
+    ));
+    test.step();
+    (test, camera)
+}
+
+#[test]
+fn driver_states_follow_the_drivers() {
+    let (mut test, camera) = camera_app();
+    let states = |test: &DollyTestApp| {
+        test.world()
+            .get::<Rig>(camera)
+            .unwrap()
+            .driver_states
+            .clone()
+    };
+    assert_eq!(
+        states(&test),
+        vec![
+            DriverState::Position { position: Vec3::X },
+            DriverState::Opaque,
+        ]
+    );
+
+    test.world_mut()
+        .get_mut::<Rig>(camera)
+        .unwrap()
+        .driver_mut::<Position>()
+        .position = Vec3::Z;
+    test.step();
+    assert_eq!(
+        states(&test)[0],
+        DriverState::Position { position: Vec3::Z }
+    );
+}
+
+#[test]
+fn reflected_edits_drive_the_rig() {
+    let (mut test, camera) = camera_app();
+
+    // Like an inspector, edit the rig through reflection
+    let mut rig = test.world_mut().get_mut::<Rig>(camera).unwrap();
+    *rig.reflect_path_mut("driver_states[0].position")
+        .unwrap()
+        .try_downcast_mut::<Vec3>()
+        .unwrap() = Vec3::Y;
+    test.step();
+
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(0., 1., 0.),
+        1e-5,
+    );
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    assert_eq!(rig.driver::<Position>().position, Vec3::Y);
+}
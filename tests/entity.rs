@@ -0,0 +1,59 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+#[test]
+fn look_at_entity_faces_the_entity() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let player = test.spawn(Transform::from_xyz(4., 0., 0.));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Position::new(Vec3::new(0., 3., 8.)))
+            .with(LookAtEntity::new(player).tracking_smoothness(0.))
+            .build(),
+    ));
+    test.run_frames(2);
+
+    let transform = test.transform(camera);
+    let to_player = (Vec3::new(4., 0., 0.) - transform.translation).normalize();
+    assert!(transform.forward().dot(to_player) > 0.9999);
+
+    // Keeps looking at the last known position once the entity is gone
+    test.world_mut().despawn(player);
+    test.run_frames(2);
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    assert!(rig.driver::<LookAtEntity>().is_lost());
+    assert!(test.transform(camera).forward().dot(to_player) > 0.9999);
+}
+
+#[test]
+fn follow_entity_reads_children_in_world_space() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let player = test.spawn(Transform::from_xyz(1., 0., 0.));
+    let parent = test.spawn(
+        Transform::from_xyz(0., 0., -5.).with_rotation(Quat::from_rotation_y(90f32.to_radians())),
+    );
+    test.world_mut().entity_mut(parent).add_child(player);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(FollowEntity::new(player).with_rotation(true))
+            .build(),
+    ));
+    test.run_frames(2);
+
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(0., 0., -6.).with_rotation(Quat::from_rotation_y(90f32.to_radians())),
+        1e-5,
+    );
+}
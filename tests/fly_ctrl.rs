@@ -0,0 +1,82 @@
+#![cfg(all(feature = "helpers", feature = "drivers"))]
+
+use bevy::{
+    input::{
+        mouse::{MouseScrollUnit, MouseWheel},
+        InputPlugin,
+    },
+    prelude::*,
+};
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn fly_app(config: DollyFlyCtrlConfig) -> (DollyTestApp, Entity) {
+    let mut test = DollyTestApp::new();
+    test.app
+        .add_plugins((InputPlugin, DollyFlyCtrl))
+        .add_dolly_component(MainCamera);
+    // Yawed to -X and pitched down 45 degrees
+    let start = Transform::IDENTITY.looking_to(Vec3::new(-1., -1., 0.), Vec3::Y);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Fpv::from_position_target(start))
+            .build(),
+        config,
+    ));
+    // Binds the input map
+    test.step();
+    (test, camera)
+}
+
+fn fpv_position(test: &DollyTestApp, camera: Entity) -> Vec3 {
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    rig.driver::<Fpv>().driver::<Position>().position
+}
+
+#[test]
+fn fly_ctrl_flies_horizontally_along_the_yaw() {
+    let (mut test, camera) = fly_app(DollyFlyCtrlConfig {
+        look_button: None,
+        world_vertical: true,
+        ..default()
+    });
+    let start = fpv_position(&test, camera);
+
+    let mut keys = test.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::KeyW);
+    test.run_frames(3);
+    let mut keys = test.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(KeyCode::KeyW);
+    test.run_frames(2);
+
+    let expected = Vec3::NEG_X * 10. * test.delta_seconds() * 3.;
+    let moved = fpv_position(&test, camera) - start;
+    assert!((moved - expected).length() < 1e-4, "{moved}");
+}
+
+#[test]
+fn fly_ctrl_scrolls_the_speed_within_its_limits() {
+    let (mut test, camera) = fly_app(DollyFlyCtrlConfig {
+        look_button: None,
+        // Swapped limits work the same
+        min_speed: 20.,
+        max_speed: 5.,
+        ..default()
+    });
+
+    for (lines, expected) in [(100., 20.), (-100., 5.)] {
+        test.world_mut().send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.,
+            y: lines,
+            window: Entity::PLACEHOLDER,
+        });
+        test.step();
+        let config = test.world().get::<DollyFlyCtrlConfig>(camera).unwrap();
+        assert_eq!(config.speed, expected);
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn move_player(test: &mut DollyTestApp, player: Entity, x: f32) {
+    test.world_mut()
+        .get_mut::<Transform>(player)
+        .unwrap()
+        .translation
+        .x = x;
+}
+
+#[test]
+fn framing_holds_still_in_the_dead_zone() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let player = test.spawn(Transform::default());
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        // 20 units across at the player's depth
+        Projection::Perspective(PerspectiveProjection {
+            fov: std::f32::consts::FRAC_PI_2,
+            aspect_ratio: 1.,
+            ..default()
+        }),
+        Rig::builder()
+            .with(Position::new(Vec3::Z * 10.))
+            .with(Framing::new(player).with_damping(0.))
+            .build(),
+    ));
+    test.run_frames(2);
+
+    move_player(&mut test, player, 0.5);
+    test.step();
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(0., 0., 10.),
+        1e-4,
+    );
+
+    // Without damping the player stays on the edge of the dead zone
+    move_player(&mut test, player, 3.);
+    test.step();
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(2., 0., 10.),
+        1e-4,
+    );
+}
+
+#[test]
+fn framing_catches_up_within_the_soft_zone() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_2d_component(MainCamera);
+    let player = test.spawn(Transform::default());
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        OrthographicProjection {
+            area: Rect::new(-100., -100., 100., 100.),
+            ..OrthographicProjection::default_2d()
+        },
+        Rig2d::builder()
+            .with(Position2d::default())
+            .with(
+                Framing::new(player)
+                    .with_dead_zone(Vec2::splat(0.1))
+                    .with_soft_zone(Vec2::splat(0.3))
+                    .with_damping(0.5),
+            )
+            .build(),
+    ));
+    test.run_frames(2);
+
+    move_player(&mut test, player, 100.);
+    let framed: Vec<f32> = test
+        .record(camera, 120)
+        .iter()
+        .map(|transform| (100. - transform.translation.x) / 100.)
+        .collect();
+    assert!(framed.iter().all(|&x| x <= 0.3 + 1e-4), "{framed:?}");
+    assert!(framed.windows(2).all(|w| w[1] <= w[0] + 1e-6));
+    assert!((framed[119] - 0.1).abs() < 1e-3, "{}", framed[119]);
+    let rig = test.world().get::<Rig2d>(camera).unwrap();
+    assert!(!rig.driver::<Framing>().is_settling());
+}
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn fov_degrees(test: &DollyTestApp, camera: Entity) -> f32 {
+    match test.world().get::<Projection>(camera).unwrap() {
+        Projection::Perspective(perspective) => perspective.fov.to_degrees(),
+        Projection::Orthographic(_) => panic!("Expected a perspective projection"),
+    }
+}
+
+#[test]
+fn fov_zoom_writes_the_field_of_view() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Projection::from(PerspectiveProjection::default()),
+        Rig::builder()
+            .with(Position::new(Vec3::Z * 5.))
+            .with_lens(FovZoom::new(60.).with_limits(20., 90.))
+            .with_lens(ClipPlanes::new(0.5, 200.))
+            .build(),
+    ));
+    test.run_frames(2);
+    assert!((fov_degrees(&test, camera) - 60.).abs() < 1e-3);
+    let Projection::Perspective(perspective) = test.world().get::<Projection>(camera).unwrap()
+    else {
+        unreachable!()
+    };
+    assert_eq!((perspective.near, perspective.far), (0.5, 200.));
+
+    // Zooming in twice halves the tangent of the half angle, within the limits
+    let mut rig = test.world_mut().get_mut::<Rig>(camera).unwrap();
+    rig.lens_driver_mut::<FovZoom>().zoom_by(2.);
+    test.step();
+    let expected = 2. * (30f32.to_radians().tan() * 0.5).atan().to_degrees();
+    assert!((fov_degrees(&test, camera) - expected).abs() < 1e-3);
+
+    let mut rig = test.world_mut().get_mut::<Rig>(camera).unwrap();
+    rig.lens_driver_mut::<FovZoom>().zoom_by(100.);
+    test.step();
+    assert!((fov_degrees(&test, camera) - 20.).abs() < 1e-3);
+}
+
+#[test]
+fn orthographic_zoom_writes_the_scale_and_leaves_perspective_alone() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let orthographic = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Projection::from(OrthographicProjection::default_3d()),
+        Rig::builder()
+            .with(Position::new(Vec3::Z * 5.))
+            .with_lens(OrthographicZoom::new(2.))
+            .build(),
+    ));
+    let perspective = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Projection::from(PerspectiveProjection::default()),
+        Rig::builder()
+            .with(Position::new(Vec3::Z * 5.))
+            .with_lens(OrthographicZoom::new(2.))
+            .build(),
+    ));
+    test.run_frames(2);
+
+    let Projection::Orthographic(projection) =
+        test.world().get::<Projection>(orthographic).unwrap()
+    else {
+        panic!("Expected an orthographic projection");
+    };
+    assert_eq!(projection.scale, 2.);
+    let default_fov = PerspectiveProjection::default().fov.to_degrees();
+    assert!((fov_degrees(&test, perspective) - default_fov).abs() < 1e-3);
+}
+
+#[test]
+fn dolly_zoom_keeps_the_subject_size_while_moving() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Projection::from(PerspectiveProjection::default()),
+        Rig::builder()
+            .with(Position::new(Vec3::Z * 6.))
+            .with_lens(DollyZoom::from_fov_degrees(Vec3::ZERO, 40., 6.))
+            .build(),
+    ));
+    test.run_frames(2);
+    assert!((fov_degrees(&test, camera) - 40.).abs() < 1e-3);
+
+    test.world_mut()
+        .get_mut::<Rig>(camera)
+        .unwrap()
+        .driver_mut::<Position>()
+        .position = Vec3::Z * 12.;
+    test.run_frames(2);
+
+    // The view is as tall at the subject as it was from half the distance
+    let height = 2. * 12. * (fov_degrees(&test, camera).to_radians() * 0.5).tan();
+    let expected = 2. * 6. * (40f32.to_radians() * 0.5).tan();
+    assert!((height - expected).abs() < 1e-3);
+}
+
+#[test]
+fn smooth_lens_eases_towards_the_new_field_of_view() {
+    let mut rig = Rig::builder()
+        .with(Position::new(Vec3::ZERO))
+        .with_lens(FovZoom::new(60.))
+        .with_lens(SmoothLens::new(1.))
+        .build();
+    rig.lens_driver_mut::<FovZoom>().fov_degrees = 30.;
+    let fovs: Vec<f32> = (0..60)
+        .map(|_| {
+            rig.update(DEFAULT_DELTA_SECONDS);
+            rig.final_lens.fov.unwrap().to_degrees()
+        })
+        .collect();
+
+    assert!(fovs[0] < 60. && fovs[0] > 30.);
+    assert!(fovs.windows(2).all(|w| w[1] <= w[0]));
+    assert!(fovs[59] - 30. < fovs[0] - 30.);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn pitch_degrees(transform: Transform) -> f32 {
+    transform.rotation.to_euler(EulerRot::YXZ).1.to_degrees()
+}
+
+#[test]
+fn yaw_pitch_limits_clamp_around_the_reference() {
+    let limits = YawPitchLimits::new()
+        .with_pitch(-30., 45.)
+        .with_yaw(Vec3::X, -60., 60.);
+    assert_eq!(limits.reference_yaw_degrees, -90.);
+    assert_eq!(limits.limit(-90., 60.), (-90., 45.));
+    assert_eq!(limits.limit(-90., -50.), (-90., -30.));
+    // Facing -Z is 90 degrees to the left of +X
+    let (yaw, _) = limits.limit(0., 0.);
+    assert!((yaw + 30.).abs() < 1e-4, "{yaw}");
+    let (yaw, _) = limits.limit(-200., 0.);
+    assert!((yaw + 150.).abs() < 1e-4, "{yaw}");
+
+    // Swapped limits are the same limits
+    let swapped = YawPitchLimits::new()
+        .with_pitch(45., -30.)
+        .with_yaw(Vec3::X, 60., -60.);
+    for (yaw, pitch) in [(-90., -50.), (0., 0.), (-200., 0.), (20., 60.)] {
+        assert_eq!(swapped.limit(yaw, pitch), limits.limit(yaw, pitch));
+    }
+}
+
+#[test]
+fn soft_yaw_pitch_limits_ease_into_the_bounds() {
+    let limits = YawPitchLimits::new()
+        .with_pitch(-30., 45.)
+        .with_softness(10.);
+    assert_eq!(limits.limit(0., 20.).1, 20.);
+    let pitches: Vec<f32> = [36., 40., 50., 90.]
+        .iter()
+        .map(|&pitch| limits.limit(0., pitch).1)
+        .collect();
+    assert!(pitches.windows(2).all(|w| w[0] < w[1]), "{pitches:?}");
+    assert!(pitches[0] > 35. && pitches[3] < 45., "{pitches:?}");
+
+    // Limits swapped through the public fields don't panic
+    let swapped = YawPitchLimits {
+        min_pitch_degrees: 45.,
+        max_pitch_degrees: -30.,
+        ..limits
+    };
+    let pitch = swapped.limit(0., 90.).1;
+    assert!(pitch > 35. && pitch < 45., "{pitch}");
+}
+
+#[test]
+fn dolly_plugin_clamps_the_yaw_pitch_input() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(YawPitch::new())
+            .with(YawPitchLimits::new().with_pitch(-30., 45.))
+            .build(),
+    ));
+
+    test.world_mut()
+        .get_mut::<Rig>(camera)
+        .unwrap()
+        .driver_mut::<YawPitch>()
+        .rotate_yaw_pitch(0., 80.);
+    test.step();
+    assert!((pitch_degrees(test.transform(camera)) - 45.).abs() < 1e-3);
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    assert_eq!(rig.driver::<YawPitch>().pitch_degrees, 45.);
+
+    // Turns back right away, without undoing the input past the limit
+    test.world_mut()
+        .get_mut::<Rig>(camera)
+        .unwrap()
+        .driver_mut::<YawPitch>()
+        .rotate_yaw_pitch(0., -10.);
+    test.step();
+    assert!((pitch_degrees(test.transform(camera)) - 35.).abs() < 1e-3);
+}
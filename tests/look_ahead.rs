@@ -0,0 +1,77 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn look_ahead_app(look_ahead: impl FnOnce(Entity) -> LookAhead) -> (DollyTestApp, Entity, Entity) {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let player = test.spawn(Transform::default());
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(FollowEntity::new(player))
+            .with(look_ahead(player))
+            .build(),
+    ));
+    test.step();
+    (test, camera, player)
+}
+
+fn lead(test: &DollyTestApp, camera: Entity, player: Entity) -> Vec3 {
+    test.transform(camera).translation - test.transform(player).translation
+}
+
+#[test]
+fn look_ahead_leads_by_the_target_velocity() {
+    let (mut test, camera, player) = look_ahead_app(|player| {
+        LookAhead::new(player)
+            .with_axes(Vec3::X)
+            .with_time(0.5)
+            .with_max_distance(3.)
+    });
+
+    test.world_mut()
+        .entity_mut(player)
+        .insert(TargetVelocity(Vec3::X * 10.));
+    test.run_frames(300);
+    assert!((lead(&test, camera, player) - Vec3::X * 3.).length() < 1e-3);
+
+    // Only along the axes
+    test.world_mut()
+        .entity_mut(player)
+        .insert(TargetVelocity(Vec3::new(2., 5., 0.)));
+    test.run_frames(300);
+    assert!((lead(&test, camera, player) - Vec3::X).length() < 1e-3);
+}
+
+#[test]
+fn look_ahead_derives_the_velocity_and_returns_to_center() {
+    let (mut test, camera, player) =
+        look_ahead_app(|player| LookAhead::new(player).with_max_distance(100.));
+
+    let mut leads = Vec::new();
+    for _ in 0..60 {
+        test.world_mut()
+            .get_mut::<Transform>(player)
+            .unwrap()
+            .translation
+            .x += 0.1;
+        test.step();
+        leads.push(lead(&test, camera, player).x);
+    }
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    let velocity = rig.driver::<LookAhead>().velocity();
+    assert!((velocity - Vec3::X * 0.1 / test.delta_seconds()).length() < 1e-2);
+    // Eases out to half a second ahead
+    assert!(leads.windows(2).all(|w| w[1] >= w[0] - 1e-4));
+    assert!((leads[59] - 0.1 / test.delta_seconds() * 0.5).abs() < 1e-3);
+
+    // Settles back on the player once it stops
+    test.run_frames(300);
+    assert!(lead(&test, camera, player).length() < 1e-3);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "drivers")]
+
+use bevy::{prelude::*, render::primitives::Aabb};
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn occlusion_app() -> (DollyTestApp, Entity, Entity) {
+    let mut test = DollyTestApp::new();
+    test.app
+        .add_plugins(DollyOcclusionPlugin::<AabbCollisionBackend>::default())
+        .add_dolly_component(MainCamera);
+    let wall = test.spawn((
+        Transform::from_xyz(0., 0., 3.),
+        Aabb::from_min_max(Vec3::new(-1., -5., -0.5), Vec3::new(1., 5., 0.5)),
+    ));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Position::new(Vec3::Z * 6.))
+            .with(LookAt::new(Vec3::ZERO))
+            .with(OcclusionOrbit::new())
+            .build(),
+        Occlusion::new().with_marked_occluders(true),
+    ));
+    (test, camera, wall)
+}
+
+#[test]
+fn occlusion_reports_and_marks_occluders() {
+    let (mut test, camera, wall) = occlusion_app();
+    let mut occluded = test
+        .world_mut()
+        .resource_mut::<Events<TargetOccluded>>()
+        .get_cursor();
+    let mut visible = test
+        .world_mut()
+        .resource_mut::<Events<TargetVisible>>()
+        .get_cursor();
+    test.run_frames(2);
+
+    let events = test.world().resource::<Events<TargetOccluded>>();
+    let events: Vec<_> = occluded
+        .read(events)
+        .map(|event| (event.rig, event.occluders.clone()))
+        .collect();
+    assert_eq!(events, [(camera, vec![wall])]);
+    assert!(test.world().get::<Occlusion>(camera).unwrap().is_occluded());
+    assert!(test.world().get::<Occluder>(wall).is_some());
+
+    test.world_mut().entity_mut(wall).remove::<Aabb>();
+    test.run_frames(2);
+    let events = test.world().resource::<Events<TargetVisible>>();
+    assert_eq!(visible.read(events).count(), 1);
+    assert!(!test.world().get::<Occlusion>(camera).unwrap().is_occluded());
+    assert!(test.world().get::<Occluder>(wall).is_none());
+}
+
+#[test]
+fn occlusion_orbit_looks_around_the_occluder() {
+    let (mut test, camera, _) = occlusion_app();
+    test.run_frames(120);
+
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    assert_ne!(rig.driver::<OcclusionOrbit>().angle_degrees(), 0.);
+    assert!(!test.world().get::<Occlusion>(camera).unwrap().is_occluded());
+    // Still looking at the target from the same distance
+    let transform = test.transform(camera);
+    assert!((transform.translation.length() - 6.).abs() < 1e-3);
+    assert!(transform.forward().dot(-transform.translation.normalize()) > 0.999);
+}
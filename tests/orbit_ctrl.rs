@@ -0,0 +1,93 @@
+#![cfg(feature = "helpers")]
+
+use bevy::{
+    input::{
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+        InputPlugin,
+    },
+    prelude::*,
+};
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn orbit_app(config: DollyOrbitCtrlConfig) -> (DollyTestApp, Entity) {
+    let mut test = DollyTestApp::new();
+    test.app
+        .add_plugins((InputPlugin, DollyOrbitCtrl))
+        .add_dolly_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Position::new(Vec3::ZERO))
+            .with(YawPitch::new())
+            .with(Arm::new(Vec3::Z * 8.))
+            .build(),
+        config,
+    ));
+    // Binds the input map
+    test.step();
+    (test, camera)
+}
+
+fn scroll(test: &mut DollyTestApp, lines: f32) {
+    test.world_mut().send_event(MouseWheel {
+        unit: MouseScrollUnit::Line,
+        x: 0.,
+        y: lines,
+        window: Entity::PLACEHOLDER,
+    });
+    test.step();
+}
+
+fn arm_length(test: &DollyTestApp, camera: Entity) -> f32 {
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    rig.driver::<Arm>().offset.length()
+}
+
+#[test]
+fn orbit_ctrl_snaps_and_clamps_the_pitch() {
+    let (mut test, camera) = orbit_app(DollyOrbitCtrlConfig {
+        // Swapped limits work the same
+        min_pitch_degrees: 60.,
+        max_pitch_degrees: -60.,
+        ..default()
+    });
+
+    // Snaps once per press
+    let mut keys = test.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::KeyX);
+    test.run_frames(3);
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    assert_eq!(rig.driver::<YawPitch>().yaw_degrees, 90.);
+
+    let mut buttons = test.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+    buttons.press(MouseButton::Left);
+    test.world_mut().send_event(MouseMotion {
+        delta: Vec2::new(0., -1000.),
+    });
+    test.step();
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    assert_eq!(rig.driver::<YawPitch>().pitch_degrees.abs(), 60.);
+}
+
+#[test]
+fn orbit_ctrl_zooms_the_arm_within_its_limits() {
+    let (mut test, camera) = orbit_app(DollyOrbitCtrlConfig {
+        min_distance: 20.,
+        max_distance: 5.,
+        ..default()
+    });
+
+    scroll(&mut test, 1.);
+    assert!((arm_length(&test, camera) - 8. / 1.1).abs() < 1e-4);
+    test.step();
+    assert!((arm_length(&test, camera) - 8. / 1.1).abs() < 1e-4);
+
+    scroll(&mut test, 100.);
+    assert!((arm_length(&test, camera) - 5.).abs() < 1e-4);
+    scroll(&mut test, -100.);
+    assert!((arm_length(&test, camera) - 20.).abs() < 1e-4);
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+#[test]
+fn pixel_snap_rounds_to_integer_zoom() {
+    let snap = PixelSnap::new().with_integer_zoom(true);
+    let zoomed = |zoom| {
+        snap.apply(&Transform2d {
+            zoom,
+            ..Transform2d::IDENTITY
+        })
+        .zoom
+    };
+    assert_eq!(zoomed(2.6), 3.);
+    assert_eq!(zoomed(1.2), 1.);
+    // Zoomed out, the inverse is rounded
+    assert_eq!(zoomed(0.45), 0.5);
+
+    let snapped = PixelSnap::new()
+        .with_units_per_pixel(2.)
+        .apply(&Transform2d {
+            position: Vec2::new(3.1, -4.9),
+            ..Transform2d::IDENTITY
+        });
+    assert_eq!(snapped.position, Vec2::new(4., -4.));
+}
+
+#[test]
+fn pixel_snapped_camera_moves_on_the_pixel_grid() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_2d_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        OrthographicProjection::default_2d(),
+        Rig2d::builder()
+            .with(Position2d::new(Vec2::ZERO))
+            .with(Zoom::new(2.6))
+            .with(Smooth2d::new_position(1.))
+            .with_pixel_snap(PixelSnap::new().with_integer_zoom(true))
+            .build(),
+    ));
+    test.step();
+    test.world_mut()
+        .get_mut::<Rig2d>(camera)
+        .unwrap()
+        .driver_mut::<Position2d>()
+        .position = Vec2::new(10.3, 0.);
+
+    // A third of a world unit per pixel at a zoom of 3
+    let frames = test.record(camera, 300);
+    for transform in &frames {
+        let pixels = transform.translation.x * 3.;
+        assert!((pixels - pixels.round()).abs() < 1e-3, "{pixels}");
+    }
+    assert!(frames
+        .windows(2)
+        .all(|w| w[1].translation.x >= w[0].translation.x));
+    assert!((frames[299].translation.x - 31. / 3.).abs() < 1e-4);
+    let projection = test.world().get::<OrthographicProjection>(camera).unwrap();
+    assert!((projection.scale - 1. / 3.).abs() < 1e-6);
+
+    // The smoothing itself isn't snapped
+    let rig = test.world().get::<Rig2d>(camera).unwrap();
+    assert!((rig.final_transform.position.x - 10.3).abs() < 1e-3);
+}
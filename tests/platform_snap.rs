@@ -0,0 +1,91 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn platformer_app(snap: impl FnOnce(Entity) -> PlatformSnap2d) -> (DollyTestApp, Entity, Entity) {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_2d_component(MainCamera);
+    let player = test.spawn((Transform::default(), PlatformerState::default()));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        OrthographicProjection::default_2d(),
+        Rig2d::builder()
+            .with(snap(player).with_damping(0., 0.))
+            .build(),
+    ));
+    test.step();
+    (test, camera, player)
+}
+
+/// Moves the character, and returns where the camera is after a frame
+fn move_player(
+    test: &mut DollyTestApp,
+    camera: Entity,
+    player: Entity,
+    y: f32,
+    grounded: bool,
+) -> Vec3 {
+    test.world_mut()
+        .get_mut::<Transform>(player)
+        .unwrap()
+        .translation = Vec3::new(10., y, 0.);
+    test.world_mut()
+        .get_mut::<PlatformerState>(player)
+        .unwrap()
+        .grounded = grounded;
+    test.step();
+    test.transform(camera).translation
+}
+
+#[test]
+fn platform_snap_anchors_on_landing() {
+    let (mut test, camera, player) =
+        platformer_app(|player| PlatformSnap2d::new(player).with_vertical_window(100., 200.));
+
+    // Follows horizontally but keeps the height of the platform in the window
+    assert_eq!(
+        move_player(&mut test, camera, player, 0., true),
+        Vec3::new(10., 0., 0.)
+    );
+    assert_eq!(move_player(&mut test, camera, player, 150., false).y, 0.);
+    assert_eq!(move_player(&mut test, camera, player, 250., false).y, 50.);
+    assert_eq!(move_player(&mut test, camera, player, 120., true).y, 120.);
+    assert_eq!(move_player(&mut test, camera, player, 50., false).y, 120.);
+    assert_eq!(move_player(&mut test, camera, player, -50., false).y, 50.);
+
+    let rig = test.world().get::<Rig2d>(camera).unwrap();
+    assert_eq!(rig.driver::<PlatformSnap2d>().anchor(), Some(50.));
+}
+
+#[test]
+fn platform_snap_treats_negative_windows_as_empty() {
+    let (mut test, camera, player) =
+        platformer_app(|player| PlatformSnap2d::new(player).with_vertical_window(-50., 200.));
+    assert_eq!(move_player(&mut test, camera, player, -30., false).y, -30.);
+
+    // Set through the public fields
+    test.world_mut()
+        .get_mut::<Rig2d>(camera)
+        .unwrap()
+        .driver_mut::<PlatformSnap2d>()
+        .window_above = -10.;
+    assert_eq!(move_player(&mut test, camera, player, 20., false).y, 20.);
+}
+
+#[test]
+fn platform_snap_leads_in_the_facing_direction() {
+    let (mut test, camera, player) =
+        platformer_app(|player| PlatformSnap2d::new(player).with_forward_focus(50., 0.));
+
+    assert_eq!(move_player(&mut test, camera, player, 0., true).x, 60.);
+    test.world_mut()
+        .get_mut::<PlatformerState>(player)
+        .unwrap()
+        .facing = -1.;
+    assert_eq!(move_player(&mut test, camera, player, 0., true).x, -40.);
+}
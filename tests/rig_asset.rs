@@ -0,0 +1,100 @@
+#![cfg(feature = "asset")]
+
+use bevy::{asset::AssetPlugin, prelude::*};
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn description(position: Vec3, arm: Vec3) -> RigDescription {
+    RigDescription {
+        drivers: vec![
+            DriverState::Position { position },
+            DriverState::Arm { offset: arm },
+        ],
+    }
+}
+
+#[test]
+fn rig_handle_builds_and_rebuilds_the_rig() {
+    let mut test = DollyTestApp::new();
+    test.app
+        .add_plugins(AssetPlugin::default())
+        .add_dolly_component(MainCamera);
+    test.app.finish();
+    let handle = test
+        .world_mut()
+        .resource_mut::<Assets<RigDescription>>()
+        .add(description(Vec3::ZERO, Vec3::Z * 2.));
+    let camera = test.spawn((MainCamera, Camera::default(), RigHandle(handle.clone())));
+    test.run_frames(2);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(0., 0., 2.),
+        1e-5,
+    );
+
+    // Gameplay moves the rig, then the description changes, ie. on a hot-reload
+    test.world_mut()
+        .get_mut::<Rig>(camera)
+        .unwrap()
+        .driver_mut::<Position>()
+        .position = Vec3::X * 5.;
+    test.step();
+    *test
+        .world_mut()
+        .resource_mut::<Assets<RigDescription>>()
+        .get_mut(&handle)
+        .unwrap() = description(Vec3::ZERO, Vec3::Z * 4.);
+    test.run_frames(2);
+
+    // The new arm, at the position gameplay left the rig at
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(5., 0., 4.),
+        1e-5,
+    );
+}
+
+#[test]
+fn rebuilt_rigs_keep_their_lens() {
+    // Without a `Dolly` system the rig is only ever updated by hand
+    let mut test = DollyTestApp::new();
+    test.app.add_plugins(AssetPlugin::default());
+    test.app.finish();
+    let handle = test
+        .world_mut()
+        .resource_mut::<Assets<RigDescription>>()
+        .add(description(Vec3::ZERO, Vec3::Z * 2.));
+    let entity = test.spawn(RigHandle(handle.clone()));
+    test.step();
+
+    let mut rig = test.world_mut().get_mut::<Rig>(entity).unwrap();
+    rig.lens_drivers.push(Box::new(FovZoom::new(30.)));
+    rig.update(DEFAULT_DELTA_SECONDS);
+    let lens = rig.final_lens;
+    assert_eq!(lens.fov, Some(30f32.to_radians()));
+
+    *test
+        .world_mut()
+        .resource_mut::<Assets<RigDescription>>()
+        .get_mut(&handle)
+        .unwrap() = description(Vec3::ZERO, Vec3::Z * 4.);
+    test.run_frames(2);
+    let rig = test.world().get::<Rig>(entity).unwrap();
+    assert_eq!(rig.driver::<Arm>().offset, Vec3::Z * 4.);
+    assert_eq!(rig.final_lens, lens);
+}
+
+#[test]
+fn rig_description_reads_ron() {
+    let parsed: RigDescription = ron::from_str(
+        "(drivers: [Position(position: (1.0, 2.0, 3.0)), Arm(offset: (0.0, 0.0, 4.0))])",
+    )
+    .unwrap();
+    assert_eq!(parsed, description(Vec3::new(1., 2., 3.), Vec3::Z * 4.));
+
+    let mut rig = parsed.build();
+    let frames = simulate_rig(&mut rig, 1, DEFAULT_DELTA_SECONDS);
+    assert_transform_near(frames[0], Transform::from_xyz(1., 2., 7.), 1e-5);
+}
@@ -0,0 +1,262 @@
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[test]
+fn rig_chains_drivers() {
+    let mut rig = Rig::builder()
+        .with(Position::new(Vec3::Y))
+        .with(YawPitch::new().yaw_degrees(90.))
+        .with(Arm::new(Vec3::Z * 4.))
+        .build();
+    let frames = simulate_rig(&mut rig, 1, DEFAULT_DELTA_SECONDS);
+
+    // Yawed to the left, so the arm points along +X
+    assert_transform_near(
+        frames[0],
+        Transform::from_xyz(4., 1., 0.).with_rotation(Quat::from_rotation_y(90f32.to_radians())),
+        1e-5,
+    );
+}
+
+#[test]
+fn rig_smoothing_is_deterministic() {
+    let mut rig = Rig::builder()
+        .with(Position::new(Vec3::ZERO))
+        .with(Smooth::new_position(1.))
+        .build();
+    rig.driver_mut::<Position>().position = Vec3::X * 10.;
+    let frames = simulate_rig(&mut rig, 4, 0.1);
+
+    assert_eq!(
+        snapshot(&frames),
+        "t: 5.507 0 0 | r: 0 0 0 1 | s: 1 1 1\n\
+         t: 7.981 0 0 | r: 0 0 0 1 | s: 1 1 1\n\
+         t: 9.093 0 0 | r: 0 0 0 1 | s: 1 1 1\n\
+         t: 9.592 0 0 | r: 0 0 0 1 | s: 1 1 1"
+    );
+}
+
+#[test]
+fn rig_substeps_match_shorter_frames() {
+    let build = |substeps: bool| {
+        let builder = Rig::builder()
+            .with(Position::new(Vec3::ZERO))
+            .with(Smooth::new_position(0.5));
+        let mut rig = if substeps {
+            builder.with_substeps(0.01, 10).build()
+        } else {
+            builder.build()
+        };
+        rig.driver_mut::<Position>().position = Vec3::X;
+        rig
+    };
+
+    let spike = simulate_rig(&mut build(true), 1, 0.1);
+    let steady = simulate_rig(&mut build(false), 10, 0.01);
+    assert_transform_near(spike[0], steady[9], 1e-5);
+}
+
+#[test]
+fn rig_2d_chains_drivers() {
+    let mut rig = Rig2d::builder()
+        .with(Position2d::new(Vec2::new(3., 4.)))
+        .with(Roll::new(0.25))
+        .with(Zoom::new(2.))
+        .build();
+    let frames = simulate_rig_2d(&mut rig, 1, DEFAULT_DELTA_SECONDS);
+
+    assert_transform_2d_near(
+        frames[0],
+        Transform2d {
+            position: Vec2::new(3., 4.),
+            roll: 0.25,
+            zoom: 2.,
+        },
+        1e-5,
+    );
+}
+
+#[test]
+fn rig_2d_smoothing_is_deterministic() {
+    let mut rig = Rig2d::builder()
+        .with(Position2d::default())
+        .with(Smooth2d::new_position(1.))
+        .build();
+    rig.driver_mut::<Position2d>().position = Vec2::new(10., -10.);
+    let frames = simulate_rig_2d(&mut rig, 3, 0.1);
+
+    assert_eq!(
+        snapshot_2d(&frames),
+        "p: 5.507 -5.507 | roll: 0 | zoom: 1\n\
+         p: 7.981 -7.981 | roll: 0 | zoom: 1\n\
+         p: 9.093 -9.093 | roll: 0 | zoom: 1"
+    );
+}
+
+#[test]
+fn rig_2d_smooths_roll_the_short_way_and_zoom_evenly() {
+    let mut rig = Rig2d::builder()
+        .with(Roll::new(3.))
+        .with(Zoom::new(1.))
+        .with(Smooth2d::new(0., 1., 1.))
+        .build();
+    rig.update(DEFAULT_DELTA_SECONDS);
+    rig.driver_mut::<Roll>().roll = -3.;
+    rig.driver_mut::<Zoom>().zoom = 4.;
+
+    // A frame that covers half the way
+    let output = rig.update(2f32.ln() / 8.);
+    let short_way = 3. + (2. * std::f32::consts::PI - 6.) / 2.;
+    assert!((output.roll - short_way).abs() < 1e-4, "{}", output.roll);
+    assert!((output.zoom - 2.).abs() < 1e-4, "{}", output.zoom);
+}
+
+#[test]
+fn rig_2d_pixel_snap_keeps_smoothed_state() {
+    let mut rig = Rig2d::builder()
+        .with(Position2d::new(Vec2::new(10.4, 0.)))
+        .with(Zoom::new(2.))
+        .with_pixel_snap(PixelSnap::new())
+        .build();
+    let output = rig.update(DEFAULT_DELTA_SECONDS);
+
+    assert_eq!(output.position, Vec2::new(10.5, 0.));
+    assert_eq!(rig.final_transform.position, Vec2::new(10.4, 0.));
+}
+
+#[cfg(feature = "drivers")]
+#[test]
+fn follow_entity_reads_the_entity() {
+    #[derive(Component)]
+    struct MainCamera;
+
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let player = test.spawn(Transform::from_xyz(1., 2., 3.));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(FollowEntity::new(player))
+            .with(Arm::new(Vec3::Z * 2.))
+            .build(),
+    ));
+    test.step();
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(1., 2., 5.),
+        1e-5,
+    );
+
+    test.world_mut()
+        .get_mut::<Transform>(player)
+        .unwrap()
+        .translation
+        .x = -1.;
+    let frames = test.record(camera, 2);
+    assert_eq!(
+        snapshot(&frames),
+        "t: -1 2 5 | r: 0 0 0 1 | s: 1 1 1\n\
+         t: -1 2 5 | r: 0 0 0 1 | s: 1 1 1"
+    );
+
+    // Stays at the last known position once the entity is gone
+    test.world_mut().despawn(player);
+    test.step();
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(-1., 2., 5.),
+        1e-5,
+    );
+}
+
+#[cfg(feature = "drivers")]
+#[test]
+fn fpv_moves_along_its_yaw_when_locked() {
+    #[derive(Component)]
+    struct MainCamera;
+
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    // Yawed to -X and pitched down 45 degrees
+    let start = Transform::IDENTITY.looking_to(Vec3::new(-1., -1., 0.), Vec3::Y);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Fpv::from_position_target(start))
+            .build(),
+    ));
+    test.run_frames(10);
+
+    let mut rig = test.world_mut().get_mut::<Rig>(camera).unwrap();
+    let fpv = rig.driver_mut::<Fpv>();
+    let free = fpv.set_position(Vec3::NEG_Z, 1., 1., false);
+    let locked = fpv.set_position(Vec3::NEG_Z, 1., 1., true);
+    assert!(free.y < -0.5);
+    assert!((locked - Vec3::NEG_X).length() < 1e-4, "{locked}");
+    fpv.driver_mut::<Position>().translate(locked * 2.);
+
+    test.run_frames(300);
+    let transform = test.transform(camera);
+    assert_transform_near(
+        Transform::from_translation(transform.translation),
+        Transform::from_xyz(-2., 0., 0.),
+        1e-3,
+    );
+    assert!(transform.forward().dot(Vec3::new(-1., -1., 0.).normalize()) > 0.999);
+}
+
+#[cfg(feature = "drivers")]
+#[test]
+fn fpv_without_yaw_pitch_moves_along_the_yaw_of_its_rotation() {
+    let mut fpv = Fpv::from(
+        bevy_dolly::dolly::rig::CameraRig::builder()
+            .with(Rotation::new(
+                Transform::IDENTITY
+                    .looking_to(Vec3::new(-1., -1., 0.), Vec3::Y)
+                    .rotation,
+            ))
+            .build(),
+    );
+    // Through the rig, `Fpv` is a driver with an `update` of its own
+    (*fpv).update(DEFAULT_DELTA_SECONDS);
+
+    let locked = fpv.set_position(Vec3::NEG_Z, 1., 1., true);
+    assert!((locked - Vec3::NEG_X).length() < 1e-4, "{locked}");
+}
+
+#[cfg(feature = "drivers")]
+#[test]
+fn movable_look_at_follows_and_faces_its_target() {
+    #[derive(Component)]
+    struct MainCamera;
+
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(MovableLookAt::from_position_target(Vec3::ZERO))
+            .build(),
+    ));
+    test.step();
+
+    test.world_mut()
+        .get_mut::<Rig>(camera)
+        .unwrap()
+        .driver_mut::<MovableLookAt>()
+        .set_position_target(Vec3::X * 5., Quat::IDENTITY);
+    test.run_frames(600);
+
+    // Behind and above the target, looking at a point above it
+    let transform = test.transform(camera);
+    assert_transform_near(
+        Transform::from_translation(transform.translation),
+        Transform::from_xyz(5., 1.5, -3.5),
+        1e-3,
+    );
+    let to_target = (Vec3::new(5., 1., 0.) - transform.translation).normalize();
+    assert!(transform.forward().dot(to_target) > 0.999);
+}
@@ -0,0 +1,133 @@
+use bevy::{asset::AssetPlugin, prelude::*};
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn sequence_app(sequence: CameraSequence, looping: bool) -> (DollyTestApp, Entity) {
+    let mut test = DollyTestApp::with_delta_seconds(0.25);
+    test.app
+        .add_plugins(AssetPlugin::default())
+        .add_dolly_component(MainCamera);
+    test.app.finish();
+    let sequence = test
+        .world_mut()
+        .resource_mut::<Assets<CameraSequence>>()
+        .add(sequence);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Position::default())
+            .with(Rotation::default())
+            .build(),
+        SequencePlayer::new(sequence).with_looping(looping),
+    ));
+    (test, camera)
+}
+
+fn marker_frames(test: &mut DollyTestApp, frames: usize) -> Vec<Vec<String>> {
+    let mut reader = test
+        .world_mut()
+        .resource_mut::<Events<SequenceMarkerReached>>()
+        .get_cursor();
+    (0..frames)
+        .map(|_| {
+            test.step();
+            reader
+                .read(test.world().resource::<Events<SequenceMarkerReached>>())
+                .map(|marker| marker.name.clone())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn sequence_moves_the_camera_along_keyframes() {
+    let (mut test, camera) = sequence_app(
+        CameraSequence::new(vec![
+            CameraKeyframe::new(0., Vec3::ZERO),
+            CameraKeyframe::new(1., Vec3::X * 4.),
+        ]),
+        false,
+    );
+    test.step();
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(1., 0., 0.),
+        1e-5,
+    );
+
+    let mut finished = test
+        .world_mut()
+        .resource_mut::<Events<SequenceFinished>>()
+        .get_cursor();
+    test.run_frames(3);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(4., 0., 0.),
+        1e-5,
+    );
+    let events = test.world().resource::<Events<SequenceFinished>>();
+    assert_eq!(finished.read(events).count(), 1);
+}
+
+#[test]
+fn sequence_reports_markers_at_the_start_and_every_loop() {
+    let (mut test, _) = sequence_app(
+        CameraSequence::new(vec![
+            CameraKeyframe::new(0., Vec3::ZERO),
+            CameraKeyframe::new(1., Vec3::X),
+        ])
+        .with_marker(0., "start")
+        .with_marker(0.5, "middle"),
+        true,
+    );
+
+    let frames = marker_frames(&mut test, 8);
+    let expected: [&[&str]; 8] = [
+        &["start"],
+        &["middle"],
+        &[],
+        &["start"],
+        &[],
+        &["middle"],
+        &[],
+        &["start"],
+    ];
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn sequence_sets_the_fov_of_the_camera_a_broadcasting_rig_drives() {
+    let mut test = DollyTestApp::with_delta_seconds(0.25);
+    test.app
+        .add_plugins(AssetPlugin::default())
+        .add_dolly_component(MainCamera);
+    test.app.finish();
+    let sequence = test
+        .world_mut()
+        .resource_mut::<Assets<CameraSequence>>()
+        .add(CameraSequence::new(vec![
+            CameraKeyframe::new(0., Vec3::ZERO).with_fov_degrees(40.),
+            CameraKeyframe::new(1., Vec3::X).with_fov_degrees(80.),
+        ]));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Projection::from(PerspectiveProjection::default()),
+    ));
+    // Not on the camera nor bound to it
+    test.spawn((
+        MainCamera,
+        Rig::builder().with(Position::default()).build(),
+        SequencePlayer::new(sequence),
+    ));
+    test.run_frames(2);
+
+    let Projection::Perspective(perspective) = test.world().get::<Projection>(camera).unwrap()
+    else {
+        panic!("Expected a perspective projection");
+    };
+    assert!((perspective.fov.to_degrees() - 60.).abs() < 1e-3);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+fn shake_frames(seed: u32) -> Vec<Transform> {
+    let mut rig = Rig::builder()
+        .with(Position::new(Vec3::ZERO))
+        .with(Shake::new().with_seed(seed))
+        .build();
+    rig.driver_mut::<Shake>().add_trauma(1.);
+    simulate_rig(&mut rig, 30, DEFAULT_DELTA_SECONDS)
+}
+
+#[test]
+fn shake_is_the_same_for_the_same_seed() {
+    let frames = shake_frames(7);
+    assert!(frames.iter().any(|frame| *frame != Transform::IDENTITY));
+    assert_eq!(frames, shake_frames(7));
+}
+
+#[test]
+fn shake_differs_between_seeds() {
+    assert_ne!(shake_frames(7), shake_frames(8));
+}
+
+#[test]
+fn trauma_decays_to_zero() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(Position::new(Vec3::Y))
+            .with(Shake::new().with_decay(2.))
+            .build(),
+    ));
+    test.step();
+
+    test.world_mut().send_event(AddTrauma::to(camera, 1.));
+    test.step();
+    let trauma = |test: &DollyTestApp| {
+        test.world()
+            .get::<Rig>(camera)
+            .unwrap()
+            .driver::<Shake>()
+            .trauma()
+    };
+    assert!(trauma(&test) > 0.9);
+    assert_ne!(test.transform(camera), Transform::from_xyz(0., 1., 0.));
+
+    // Trauma of 1 is gone after half a second
+    test.run_frames(31);
+    assert_eq!(trauma(&test), 0.);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(0., 1., 0.),
+        1e-6,
+    );
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+const VIEW: Rect = Rect {
+    min: Vec2::new(-100., -50.),
+    max: Vec2::new(100., 50.),
+};
+
+/// Scales the visible area with the projection, like the camera system of the renderer
+fn update_view_area(mut projections: Query<&mut OrthographicProjection>) {
+    for mut projection in &mut projections {
+        projection.area =
+            Rect::from_corners(VIEW.min * projection.scale, VIEW.max * projection.scale);
+    }
+}
+
+#[test]
+fn target_group_frames_every_target() {
+    let mut test = DollyTestApp::new();
+    test.app
+        .add_dolly_2d_component(MainCamera)
+        .add_systems(PostUpdate, update_view_area.after(DollyUpdateSet));
+    let left = test.spawn(Transform::from_xyz(-40., 0., 0.));
+    let right = test.spawn(Transform::from_xyz(60., 0., 0.));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        OrthographicProjection {
+            area: VIEW,
+            ..OrthographicProjection::default_2d()
+        },
+        Rig2d::builder()
+            .with(Position2d::default())
+            .with(
+                TargetGroup::new()
+                    .with_target(left, 1., 0.)
+                    .with_target(right, 1., 0.)
+                    .with_padding(10.),
+            )
+            .build(),
+    ));
+    test.run_frames(2);
+
+    // Centered between the targets, zoomed so the wider half extent of 60 fills the view
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(10., 0., 0.),
+        1e-3,
+    );
+    let scale = test
+        .world()
+        .get::<OrthographicProjection>(camera)
+        .unwrap()
+        .scale;
+    assert!((scale - 0.6).abs() < 1e-4, "{scale}");
+
+    // Despawned targets are dropped from the group
+    test.world_mut().despawn(left);
+    test.run_frames(2);
+    assert_transform_near(
+        test.transform(camera),
+        Transform::from_xyz(60., 0., 0.),
+        1e-3,
+    );
+}
+
+#[test]
+fn target_group_leans_towards_heavier_targets() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let light = test.spawn(Transform::from_xyz(-3., 0., 0.));
+    let heavy = test.spawn(Transform::from_xyz(3., 0., 0.));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Projection::default(),
+        Rig::builder()
+            .with(
+                TargetGroup::new()
+                    .with_target(light, 1., 0.)
+                    .with_target(heavy, 2., 0.)
+                    .with_distance_limits(20., 10.),
+            )
+            .build(),
+    ));
+    test.run_frames(2);
+
+    // At the weighted center, backed off within the swapped distance limits
+    let translation = test.transform(camera).translation;
+    assert!((translation.x - 1.).abs() < 1e-4, "{translation}");
+    assert!((10. ..=20.).contains(&translation.z), "{translation}");
+}
+
+#[test]
+fn broadcast_rigs_zoom_the_camera_they_drive() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let left = test.spawn(Transform::from_xyz(-40., 0., 0.));
+    let right = test.spawn(Transform::from_xyz(60., 0., 0.));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Projection::Orthographic(OrthographicProjection {
+            area: VIEW,
+            ..OrthographicProjection::default_3d()
+        }),
+    ));
+    // Not on the camera nor bound to it
+    test.spawn((
+        MainCamera,
+        Rig::builder()
+            .with(
+                TargetGroup::new()
+                    .with_target(left, 1., 0.)
+                    .with_target(right, 1., 0.)
+                    .with_padding(10.),
+            )
+            .build(),
+    ));
+    test.step();
+
+    let Projection::Orthographic(projection) = test.world().get::<Projection>(camera).unwrap()
+    else {
+        panic!("Expected an orthographic projection");
+    };
+    assert!(
+        (projection.scale - 0.6).abs() < 1e-4,
+        "{}",
+        projection.scale
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+#[derive(Component)]
+struct Body;
+
+fn move_bodies(mut bodies: Query<&mut Transform, With<Body>>) {
+    for mut transform in &mut bodies {
+        transform.translation.x += 1.;
+    }
+}
+
+#[test]
+fn fixed_step_target_is_followed_in_between_steps() {
+    let mut test = DollyTestApp::with_delta_seconds(0.025);
+    test.app
+        .insert_resource(Time::<Fixed>::from_hz(10.))
+        .add_systems(FixedUpdate, move_bodies)
+        .add_dolly_component(MainCamera);
+    let body = test.spawn((Body, FixedStepTarget::default()));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder().with(FollowEntity::new(body)).build(),
+    ));
+
+    let mut followed = Vec::new();
+    for _ in 0..12 {
+        test.step();
+        let body_x = test.transform(body).translation.x;
+        // Only fixed-step systems move the body
+        assert_eq!(body_x, body_x.round());
+        followed.push(test.transform(camera).translation.x);
+    }
+
+    // Moves a quarter of a fixed step every frame once two fixed steps are recorded
+    for step in followed[7..].windows(2) {
+        assert!((step[1] - step[0] - 0.25).abs() < 1e-4, "{followed:?}");
+    }
+}
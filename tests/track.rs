@@ -0,0 +1,63 @@
+#![cfg(feature = "drivers")]
+
+use bevy::prelude::*;
+use bevy_dolly::{prelude::*, testing::*};
+
+#[derive(Component)]
+struct MainCamera;
+
+#[test]
+fn dolly_track_places_the_rig_on_the_path() {
+    let mut test = DollyTestApp::new();
+    test.app.add_dolly_component(MainCamera);
+    let path = test.spawn((
+        DollyTrackPath::catmull_rom(vec![Vec3::ZERO, Vec3::X * 10., Vec3::X * 20.]),
+        Transform::from_xyz(0., 1., 0.),
+    ));
+    let player = test.spawn(Transform::from_xyz(7., 5., 0.));
+    let camera = test.spawn((
+        MainCamera,
+        Camera::default(),
+        Rig::builder()
+            .with(
+                DollyTrack::new(path)
+                    .at_normalized(0.5)
+                    .with_tangent_rotation(true),
+            )
+            .build(),
+    ));
+    test.run_frames(2);
+
+    // Halfway along a straight path in the space of the path entity, facing along it
+    let transform = test.transform(camera);
+    assert!(transform.translation.distance(Vec3::new(10., 1., 0.)) < 1e-2);
+    assert!(transform.forward().dot(Vec3::X) > 0.999);
+    let rig = test.world().get::<Rig>(camera).unwrap();
+    assert!((rig.driver::<DollyTrack>().length() - 20.).abs() < 1e-2);
+
+    // The closest point to an entity follows it along the path
+    test.world_mut()
+        .get_mut::<Rig>(camera)
+        .unwrap()
+        .driver_mut::<DollyTrack>()
+        .position = TrackPosition::Closest(player);
+    test.run_frames(2);
+    assert!(
+        test.transform(camera)
+            .translation
+            .distance(Vec3::new(7., 1., 0.))
+            < 1e-2
+    );
+    test.world_mut()
+        .get_mut::<Transform>(player)
+        .unwrap()
+        .translation
+        .x = 15.;
+    test.run_frames(2);
+    assert!(
+        test.transform(camera)
+            .translation
+            .distance(Vec3::new(15., 1., 0.))
+            < 1e-2
+    );
+}